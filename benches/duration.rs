@@ -0,0 +1,69 @@
+use std::fmt::Write;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use time::{Duration, HOUR, MICROSECOND, MILLISECOND, MINUTE, NANOSECOND, SECOND};
+
+/// Durations exercising each branch of the formatting and parsing code.
+fn durations() -> Vec<(&'static str, Duration)> {
+    vec![
+        ("zero", Duration(0)),
+        ("ns", 7 * NANOSECOND),
+        ("us", 1500 * NANOSECOND),
+        ("ms", 1234567 * NANOSECOND),
+        ("s", 30 * SECOND + 950 * MILLISECOND),
+        (
+            "hms",
+            HOUR + 15 * MINUTE + 30 * SECOND + 918273645 * NANOSECOND,
+        ),
+        ("min", Duration(i64::MIN)),
+        ("round", 2 * HOUR + 30 * MINUTE + 5 * MICROSECOND),
+    ]
+}
+
+fn display(c: &mut Criterion) {
+    let mut group = c.benchmark_group("display");
+    for (name, d) in durations() {
+        group.bench_with_input(BenchmarkId::new("to_string", name), &d, |b, d| {
+            b.iter(|| black_box(d).to_string())
+        });
+        group.bench_with_input(BenchmarkId::new("format_into", name), &d, |b, d| {
+            let mut s = String::with_capacity(32);
+            b.iter(|| {
+                s.clear();
+                black_box(d).format_into(&mut s).unwrap();
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("precision", name), &d, |b, d| {
+            let mut s = String::with_capacity(32);
+            b.iter(|| {
+                s.clear();
+                write!(s, "{:.3}", black_box(d)).unwrap();
+            })
+        });
+    }
+    group.finish();
+}
+
+fn from_str(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_str");
+    for (name, d) in durations() {
+        let s = d.to_string();
+        group.bench_with_input(BenchmarkId::new("parse", name), s.as_str(), |b, s| {
+            b.iter(|| black_box(s).parse::<Duration>())
+        });
+    }
+    for s in [
+        "1h2m3s4ms5us6ns",
+        "0.830103483285477580700h",
+        "9223372036854775807ns",
+    ] {
+        group.bench_with_input(BenchmarkId::new("parse", s), s, |b, s| {
+            b.iter(|| black_box(s).parse::<Duration>())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, display, from_str);
+criterion_main!(benches);
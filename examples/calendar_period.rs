@@ -0,0 +1,21 @@
+use time::calendar::{add_period, days_between, period_between};
+use time::{Month, Period};
+
+fn main() {
+    let start = (2024, Month::January, 31);
+    // Days past the end of February roll over into March.
+    assert_eq!(
+        (2024, Month::March, 2),
+        add_period(start, Period::new(0, 1, 0))
+    );
+    assert_eq!(
+        (2025, Month::March, 3),
+        add_period(start, Period::new(1, 1, 0))
+    );
+
+    let end = (2025, Month::March, 15);
+    assert_eq!(409, days_between(start, end));
+    let p = period_between(start, end);
+    assert_eq!("1y1mo12d", p.to_string());
+    assert_eq!(end, add_period(start, p));
+}
@@ -0,0 +1,19 @@
+use time::{DateTime, Month, Period, HOUR};
+
+fn main() {
+    let dt: DateTime = "2024-01-31T22:30:00".parse().unwrap();
+    assert_eq!((2024, Month::January, 31), dt.to_calendar_date());
+    assert_eq!((22, 30), (dt.hour(), dt.minute()));
+
+    // Durations roll over midnight, and periods keep the time of day.
+    let later = dt.checked_add(2 * HOUR).unwrap();
+    assert_eq!("2024-02-01T00:30:00", later.to_string());
+    assert_eq!(Some(2 * HOUR), later.duration_since(&dt));
+    assert_eq!(
+        "2024-03-02T22:30:00",
+        dt.add_period(Period::new(0, 1, 0)).to_string()
+    );
+
+    let noon = DateTime::new(2024, Month::March, 10, 12, 0, 0, 500_000_000).unwrap();
+    assert_eq!("2024-03-10T12:00:00.5", noon.to_string());
+}
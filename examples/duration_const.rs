@@ -0,0 +1,14 @@
+use time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_seconds(30);
+const SESSION_TTL: Duration = Duration::from_hours(2);
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+fn main() {
+    assert_eq!("30s", REQUEST_TIMEOUT.to_string());
+    assert_eq!("2h0m0s", SESSION_TTL.to_string());
+    assert_eq!("250ms", POLL_INTERVAL.to_string());
+
+    const TTL_MILLIS: i64 = SESSION_TTL.milliseconds();
+    assert_eq!(7_200_000, TTL_MILLIS);
+}
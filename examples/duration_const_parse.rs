@@ -0,0 +1,9 @@
+use time::{Duration, MILLISECOND, SECOND};
+
+const TIMEOUT: Duration = Duration::const_parse("30s");
+const BACKOFF: Duration = Duration::const_parse("1.5s");
+
+fn main() {
+    assert_eq!(30 * SECOND, TIMEOUT);
+    assert_eq!(1500 * MILLISECOND, BACKOFF);
+}
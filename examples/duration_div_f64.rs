@@ -0,0 +1,8 @@
+fn main() {
+    let d = 3 * time::SECOND;
+    assert_eq!("2s", d.div_f64(1.5).to_string());
+    assert_eq!("-1.5s", d.div_f64(-2.0).to_string());
+
+    // Dividing by zero saturates.
+    assert_eq!(i64::MAX, d.div_f64(0.0).nanoseconds());
+}
@@ -0,0 +1,8 @@
+use std::fmt::Write;
+
+fn main() {
+    let mut line = String::from("elapsed=");
+    (90 * time::MINUTE).format_into(&mut line).unwrap();
+    write!(line, " status=ok").unwrap();
+    assert_eq!("elapsed=1h30m0s status=ok", line);
+}
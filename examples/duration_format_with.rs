@@ -0,0 +1,21 @@
+use time::{Duration, DurationUnit, FormatOptions};
+
+fn main() {
+    let d: Duration = "1h15m30.918273645s".parse().unwrap();
+
+    let opts = FormatOptions::new().with_largest_unit(DurationUnit::Minute);
+    assert_eq!("75m30.918273645s", d.format_with(&opts));
+
+    let opts = FormatOptions::new().with_smallest_unit(DurationUnit::Millisecond);
+    assert_eq!("1h15m30.918s", d.format_with(&opts));
+
+    let opts = FormatOptions::new()
+        .with_largest_unit(DurationUnit::Minute)
+        .with_smallest_unit(DurationUnit::Minute);
+    assert_eq!("76m", d.format_with(&opts));
+
+    let opts = FormatOptions::new()
+        .with_smallest_unit(DurationUnit::Second)
+        .with_separator(" ");
+    assert_eq!("1h 15m 31s", d.format_with(&opts));
+}
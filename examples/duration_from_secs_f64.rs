@@ -0,0 +1,10 @@
+fn main() {
+    let d = time::Duration::from_secs_f64(2.5).unwrap();
+    assert_eq!("2.5s", d.to_string());
+
+    let d = time::Duration::from_secs_f64(-0.000_000_001_5).unwrap();
+    assert_eq!("-2ns", d.to_string());
+
+    assert!(time::Duration::from_secs_f64(f64::NAN).is_err());
+    assert!(time::Duration::from_secs_f64(1e20).is_err());
+}
@@ -0,0 +1,13 @@
+use rand::Rng;
+use time::{MILLISECOND, SECOND};
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    // Jitter a retry delay of one second by up to 500ms.
+    let delay = SECOND + rng.gen_range(-500 * MILLISECOND..=500 * MILLISECOND);
+    assert!(delay >= 500 * MILLISECOND && delay <= 1500 * MILLISECOND);
+
+    let d = rng.gen_range(SECOND..5 * SECOND);
+    assert!(d >= SECOND && d < 5 * SECOND);
+}
@@ -0,0 +1,11 @@
+fn main() {
+    let d = 2 * time::HOUR;
+    assert_eq!("2 hours", d.humanize());
+
+    let d = 75 * time::HOUR;
+    assert_eq!("about 3 days", d.humanize());
+
+    let d = 5 * time::MINUTE;
+    assert_eq!("in 5 minutes", d.humanize_relative());
+    assert_eq!("5 minutes ago", (-d).humanize_relative());
+}
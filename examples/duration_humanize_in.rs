@@ -0,0 +1,9 @@
+use time::Locale;
+
+fn main() {
+    let d = 3 * time::HOUR;
+    assert_eq!("3 Stunden", d.humanize_in(Locale::German));
+    assert_eq!("3 часа", d.humanize_in(Locale::Russian));
+    assert_eq!("il y a 3 heures", (-d).humanize_relative_in(Locale::French));
+    assert_eq!("3小时后", d.humanize_relative_in(Locale::Chinese));
+}
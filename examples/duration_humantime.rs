@@ -0,0 +1,8 @@
+fn main() {
+    let d = time::Duration::parse_humantime("2days 3hours 15min").unwrap();
+    assert_eq!("51h15m0s", d.to_string());
+    assert_eq!("2days 3h 15m", d.to_humantime());
+
+    let d = time::Duration::parse_humantime("1month 500ms").unwrap();
+    assert_eq!("1month 500ms", d.to_humantime());
+}
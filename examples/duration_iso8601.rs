@@ -0,0 +1,13 @@
+use time::Duration;
+
+fn main() {
+    let d = Duration::parse_iso8601("PT1H30M").unwrap();
+    assert_eq!("1h30m0s", d.to_string());
+    assert_eq!("PT1H30M", d.to_iso8601());
+
+    let d = Duration::parse_iso8601("-P1DT0.5S").unwrap();
+    assert_eq!("-PT24H0.5S", d.to_iso8601());
+
+    // Years and months don't have a fixed length.
+    assert!(Duration::parse_iso8601("P1M").is_err());
+}
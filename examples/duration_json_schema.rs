@@ -0,0 +1,16 @@
+use time::Duration;
+
+#[derive(schemars::JsonSchema)]
+#[allow(dead_code)]
+struct Config {
+    timeout: Duration,
+}
+
+fn main() {
+    let schema = schemars::schema_for!(Config);
+    let json = serde_json::to_value(&schema).unwrap();
+
+    let duration = &json["definitions"]["Duration"];
+    assert_eq!("string", duration["type"]);
+    assert_eq!("1h30m0s", duration["examples"][0]);
+}
@@ -0,0 +1,8 @@
+use time::{duration, Duration, MINUTE, SECOND};
+
+const REQUEST_TIMEOUT: Duration = duration!("1m30s");
+
+fn main() {
+    assert_eq!(MINUTE + 30 * SECOND, REQUEST_TIMEOUT);
+    assert_eq!("-1.5s", duration!("-1.5s").to_string());
+}
@@ -0,0 +1,10 @@
+use time::{Duration, MICROSECOND, MILLISECOND};
+
+fn main() {
+    let latency = 2 * MILLISECOND + 700 * MICROSECOND;
+
+    assert_eq!(2, latency.milliseconds());
+    assert_eq!(3, latency.milliseconds_rounded());
+    assert_eq!(-3, (-latency).milliseconds_rounded());
+    assert_eq!(2, Duration(2_499_999).milliseconds_rounded());
+}
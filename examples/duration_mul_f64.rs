@@ -0,0 +1,8 @@
+fn main() {
+    let d = 2 * time::SECOND;
+    assert_eq!("3s", d.mul_f64(1.5).to_string());
+    assert_eq!("-500ms", d.mul_f64(-0.25).to_string());
+
+    // Results out of range saturate.
+    assert_eq!(i64::MAX, d.mul_f64(1e300).nanoseconds());
+}
@@ -0,0 +1,11 @@
+fn main() {
+    let d = time::Duration::new(5, 30);
+    assert_eq!("5.00000003s", d.to_string());
+
+    // Nanoseconds beyond a second carry into the seconds.
+    let d = time::Duration::new(1, 1_500_000_000);
+    assert_eq!("2.5s", d.to_string());
+
+    let d = time::Duration::new(-1, -500_000_000);
+    assert_eq!("-1.5s", d.to_string());
+}
@@ -0,0 +1,11 @@
+use time::{Duration, MILLISECOND, SECOND};
+
+fn main() {
+    let frame: &[u8] = b"timeout=1.5s;retry=250ms";
+
+    let timeout = Duration::parse_bytes(&frame[8..12]).unwrap();
+    assert_eq!(SECOND + 500 * MILLISECOND, timeout);
+
+    let retry = Duration::parse_bytes(&frame[19..]).unwrap();
+    assert_eq!(250 * MILLISECOND, retry);
+}
@@ -0,0 +1,12 @@
+use time::Duration;
+
+fn main() {
+    let d = Duration::parse_clock("01:30:05.250").unwrap();
+    assert_eq!("1h30m5.25s", d.to_string());
+
+    let d = Duration::parse_clock("-30:05").unwrap();
+    assert_eq!("-30m5s", d.to_string());
+
+    // Minutes and seconds must be below 60.
+    assert!(Duration::parse_clock("1:60:00").is_err());
+}
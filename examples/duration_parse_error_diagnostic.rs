@@ -0,0 +1,12 @@
+use time::Duration;
+
+fn main() {
+    let input = "1h30x";
+    let err = input.parse::<Duration>().unwrap_err();
+
+    assert_eq!(4, err.offset());
+    assert_eq!(
+        "1h30x\n    ^ unknown unit \"x\" (valid units: ns, us/µs/μs, ms, s, m, h)",
+        err.diagnostic(input)
+    );
+}
@@ -0,0 +1,15 @@
+use time::Duration;
+
+fn main() {
+    let input = "1x";
+    let err = input.parse::<Duration>().unwrap_err();
+
+    assert_eq!(
+        r#"unknown unit "x" (valid units: ns, us/µs/μs, ms, s, m, h)"#,
+        err.to_string()
+    );
+    assert_eq!(
+        r#"time: unknown unit "x" in duration "1x""#,
+        err.go_message(input)
+    );
+}
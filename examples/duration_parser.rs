@@ -0,0 +1,11 @@
+fn main() {
+    let parser = time::DurationParser::new()
+        .with_unit("mo", 30 * 24 * time::HOUR)
+        .without_unit("m");
+
+    let d = parser.parse("1mo12h").unwrap();
+    assert_eq!("732h0m0s", d.to_string());
+
+    // "m" is no longer a valid unit.
+    assert!(parser.parse("30m").is_err());
+}
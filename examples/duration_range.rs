@@ -0,0 +1,13 @@
+use time::{Duration, DurationRange, MILLISECOND, SECOND};
+
+fn main() {
+    let edges: Vec<String> = DurationRange::new(Duration(0), SECOND, 250 * MILLISECOND)
+        .map(|d| d.to_string())
+        .collect();
+    assert_eq!(vec!["0s", "250ms", "500ms", "750ms"], edges);
+
+    let countdown: Vec<String> = DurationRange::new(3 * SECOND, Duration(0), -SECOND)
+        .map(|d| d.to_string())
+        .collect();
+    assert_eq!(vec!["3s", "2s", "1s"], countdown);
+}
@@ -0,0 +1,13 @@
+use time::{HOUR, MINUTE, SECOND};
+
+fn main() {
+    let budget = HOUR;
+    let spent = 15 * MINUTE;
+
+    assert_eq!(0.25, spent.div_duration_f64(budget));
+    assert_eq!(25.0, spent.percent_of(budget));
+
+    // Three quarters of the budget, without going through floats.
+    assert_eq!(45 * MINUTE, budget.mul_ratio(3, 4));
+    assert_eq!(333_333_333, SECOND.mul_ratio(1, 3).nanoseconds());
+}
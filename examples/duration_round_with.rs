@@ -0,0 +1,16 @@
+use time::{Duration, RoundingMode};
+
+fn main() {
+    let d: Duration = "2m30s".parse().unwrap();
+
+    let modes = [
+        (RoundingMode::Ceil, "3m0s"),
+        (RoundingMode::Floor, "2m0s"),
+        (RoundingMode::HalfAwayFromZero, "3m0s"),
+        (RoundingMode::HalfEven, "2m0s"),
+    ];
+
+    for (mode, want) in modes {
+        assert_eq!(want, d.round_with(time::MINUTE, mode).to_string());
+    }
+}
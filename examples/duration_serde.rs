@@ -0,0 +1,15 @@
+use time::{Duration, MILLISECOND, MINUTE, SECOND};
+
+fn main() {
+    let d = 90 * MINUTE + 500 * MILLISECOND;
+    assert_eq!(r#""1h30m0.5s""#, serde_json::to_string(&d).unwrap());
+
+    let d: Duration = serde_json::from_str(r#""1h30m""#).unwrap();
+    assert_eq!(90 * MINUTE, d);
+
+    let d: Duration = serde_json::from_str("5000000000").unwrap();
+    assert_eq!(5 * SECOND, d);
+
+    let d: Duration = serde_json::from_str(r#"{"secs": 5, "nanos": 500000000}"#).unwrap();
+    assert_eq!(5500 * MILLISECOND, d);
+}
@@ -0,0 +1,17 @@
+use time::{DurationStats, MILLISECOND};
+
+fn main() {
+    let mut stats = DurationStats::with_quantiles();
+    for ms in 1..=100 {
+        stats.push(ms * MILLISECOND);
+    }
+
+    assert_eq!(100, stats.count());
+    assert_eq!(Some(MILLISECOND), stats.min());
+    assert_eq!(Some(100 * MILLISECOND), stats.max());
+    assert_eq!(Some(50500 * time::MICROSECOND), stats.mean());
+
+    // Quantiles are estimated within about 3%.
+    let p99 = stats.quantile(0.99).unwrap();
+    assert!((p99 - 99 * MILLISECOND).abs() < 3 * MILLISECOND, "{p99}");
+}
@@ -0,0 +1,22 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use time::{duration_strategy, Duration, MINUTE, SECOND};
+
+fn main() {
+    let mut runner = TestRunner::default();
+
+    runner
+        .run(&duration_strategy(SECOND..MINUTE), |d| {
+            let t = d.truncate(SECOND);
+            prop_assert!(t >= SECOND && t < MINUTE);
+            Ok(())
+        })
+        .unwrap();
+
+    runner
+        .run(&any::<Duration>(), |d| {
+            prop_assert_eq!(d, d.to_string().parse::<Duration>().unwrap());
+            Ok(())
+        })
+        .unwrap();
+}
@@ -0,0 +1,10 @@
+fn main() {
+    let d = 90 * time::MINUTE;
+
+    let b = d.to_bytes();
+    assert_eq!([1, 0x80, 0, 0x04, 0xe9, 0x49, 0x14, 0xf0, 0], b);
+    assert_eq!(Ok(d), time::Duration::from_bytes(&b));
+
+    // Encodings sort like the durations they encode.
+    assert!((-d).to_bytes() < d.to_bytes());
+}
@@ -0,0 +1,8 @@
+fn main() {
+    let d = time::MILLISECOND;
+    std::thread::sleep(d.to_std().unwrap());
+
+    let d = -time::SECOND;
+    assert!(d.to_std().is_err());
+    assert_eq!(std::time::Duration::from_secs(1), d.to_std_abs());
+}
@@ -0,0 +1,33 @@
+use clap::{value_parser, Arg, Command};
+use time::{Duration, DurationParser, DurationValueParser, DAY, MINUTE, SECOND};
+
+fn main() {
+    let cmd = Command::new("server")
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_parser(value_parser!(Duration)),
+        )
+        .arg(
+            Arg::new("retention")
+                .long("retention")
+                .value_parser(DurationValueParser::from(
+                    DurationParser::new().with_unit("d", DAY),
+                )),
+        );
+
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["server", "--timeout", "1m30s", "--retention", "7d"])
+        .unwrap();
+    assert_eq!(
+        Some(&(MINUTE + 30 * SECOND)),
+        matches.get_one::<Duration>("timeout")
+    );
+    assert_eq!(Some(&(7 * DAY)), matches.get_one::<Duration>("retention"));
+
+    let err = cmd
+        .try_get_matches_from(["server", "--timeout", "30x"])
+        .unwrap_err();
+    println!("{err}");
+}
@@ -0,0 +1,23 @@
+use time::{Holiday, HolidayCalendar, Month};
+
+fn main() {
+    let us = HolidayCalendar::US_FEDERAL;
+    // Thanksgiving, and July 4, 2026 observed on Friday, July 3.
+    assert!(us.is_holiday(2024, Month::November, 28));
+    assert!(!us.is_business_day(2026, Month::July, 3));
+
+    // Good Friday and Easter Monday close TARGET2.
+    let target2 = HolidayCalendar::TARGET2;
+    assert_eq!(
+        (2024, Month::April, 2),
+        target2.add_business_days(2024, Month::March, 28, 1)
+    );
+
+    // Custom calendars extend a preset or start empty.
+    let company = HolidayCalendar::US_FEDERAL.with_holiday(Holiday::Fixed {
+        month: Month::December,
+        day: 24,
+        observed: false,
+    });
+    assert!(company.is_holiday(2024, Month::December, 24));
+}
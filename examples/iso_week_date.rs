@@ -0,0 +1,15 @@
+use time::{IsoWeekDate, Month, Weekday};
+
+fn main() {
+    // 2024-12-30 is the Monday of the first week of 2025.
+    let d = IsoWeekDate::from_calendar_date(2024, Month::December, 30).unwrap();
+    assert_eq!(
+        (2025, 1, Weekday::Monday),
+        (d.year(), d.week(), d.weekday())
+    );
+    assert_eq!("2025-W01-1", d.to_string());
+    assert_eq!((2024, Month::December, 30), d.to_calendar_date());
+
+    assert_eq!(53, IsoWeekDate::weeks_in_year(2020));
+    assert!(IsoWeekDate::new(2021, 53, Weekday::Monday).is_none());
+}
@@ -0,0 +1,12 @@
+use time::{Duration, LongDuration};
+
+fn main() {
+    let d = LongDuration::from(time::HOUR);
+    assert_eq!("1h0m0s", d.to_string());
+
+    // A millennium doesn't fit in a Duration.
+    let millennium = LongDuration(1000 * 365 * 24 * time::HOUR.0 as i128);
+    assert_eq!("8760000h0m0s", millennium.to_string());
+    assert!(Duration::try_from(millennium).is_err());
+    assert_eq!(Ok(time::HOUR), Duration::try_from(d).map_err(|_| ()));
+}
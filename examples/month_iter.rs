@@ -0,0 +1,8 @@
+use time::Month;
+
+fn main() {
+    let quarter_ends: Vec<&str> = Month::iter().skip(2).step_by(3).map(|m| m.abbr()).collect();
+    assert_eq!(vec!["Mar", "Jun", "Sep", "Dec"], quarter_ends);
+
+    assert_eq!(Some(Month::December), Month::iter().next_back());
+}
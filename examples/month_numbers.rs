@@ -0,0 +1,9 @@
+use time::Month;
+
+fn main() {
+    assert_eq!(Month::March, Month::try_from(3).unwrap());
+    assert_eq!(Month::December, Month::try_from(12u8).unwrap());
+    assert!(Month::try_from(13).is_err());
+
+    assert_eq!(1, i32::from(Month::January));
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use time::{Month, Weekday};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Schedule {
+    month: Month,
+    #[serde(with = "time::serde::weekday_number")]
+    weekday: Weekday,
+}
+
+fn main() {
+    let s = Schedule {
+        month: Month::March,
+        weekday: Weekday::Monday,
+    };
+
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(r#"{"month":"March","weekday":1}"#, json);
+    assert_eq!(s, serde_json::from_str(&json).unwrap());
+
+    // Names and numbers are both accepted by default.
+    let m: Month = serde_json::from_str("3").unwrap();
+    assert_eq!(Month::March, m);
+}
@@ -0,0 +1,10 @@
+fn main() {
+    let week = time::parse_duration_extended("1w").unwrap();
+    let mixed = time::parse_duration_extended("3d12h").unwrap();
+
+    assert_eq!(7 * time::DAY, week);
+    assert_eq!("84h0m0s", mixed.to_string());
+
+    // The plain parser doesn't know about days.
+    assert!(time::parse_duration("3d12h").is_err());
+}
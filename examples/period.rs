@@ -0,0 +1,12 @@
+use time::Period;
+
+fn main() {
+    let p: Period = "1y14mo3d".parse().unwrap();
+    assert_eq!(Period::new(1, 14, 3), p);
+    assert_eq!("2y2mo3d", p.normalized().to_string());
+    assert_eq!("P2Y2M3D", p.normalized().to_iso8601());
+
+    assert_eq!(p, Period::parse_iso8601("P1Y14M3D").unwrap());
+    assert_eq!(Period::new(0, 1, 14), "1mo2w".parse().unwrap());
+    assert_eq!("-1y6mo", (-Period::new(1, 6, 0)).to_string());
+}
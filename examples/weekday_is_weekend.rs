@@ -0,0 +1,10 @@
+use time::{Weekday, WeekendPolicy};
+
+fn main() {
+    assert!(Weekday::Sunday.is_weekend());
+    assert!(!Weekday::Friday.is_weekend());
+
+    let policy = WeekendPolicy::FRIDAY_SATURDAY;
+    assert!(Weekday::Friday.is_weekend_in(policy));
+    assert!(!Weekday::Sunday.is_weekend_in(policy));
+}
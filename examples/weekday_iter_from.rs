@@ -0,0 +1,13 @@
+use time::Weekday;
+
+fn main() {
+    let header: Vec<&str> = Weekday::iter_from(Weekday::Monday)
+        .map(|d| d.abbr())
+        .collect();
+    assert_eq!(
+        vec!["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        header
+    );
+
+    assert_eq!(Some(Weekday::Sunday), Weekday::iter().next());
+}
@@ -0,0 +1,12 @@
+use time::{Month, Weekday};
+
+fn main() {
+    // US daylight saving time starts on the second Sunday of March.
+    let day = Weekday::Sunday.nth_of_month(2024, Month::March, 2);
+    assert_eq!(Some(10), day);
+
+    let last_friday = Weekday::Friday.nth_of_month(2024, Month::March, -1);
+    assert_eq!(Some(29), last_friday);
+
+    assert_eq!(None, Weekday::Friday.nth_of_month(2024, Month::March, 6));
+}
@@ -0,0 +1,12 @@
+use time::Weekday;
+
+fn main() {
+    // Go numbers weekdays from Sunday = 0.
+    assert_eq!(Weekday::Sunday, Weekday::try_from(0).unwrap());
+    assert_eq!(1, i32::from(Weekday::Monday));
+
+    // ISO 8601 numbers them from Monday = 1 to Sunday = 7.
+    assert_eq!(Weekday::Sunday, Weekday::from_iso(7).unwrap());
+    assert_eq!(7, Weekday::Sunday.to_iso());
+    assert!(Weekday::from_iso(0).is_err());
+}
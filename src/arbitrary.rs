@@ -0,0 +1,29 @@
+//! Implementations of arbitrary's Arbitrary for the types of this crate,
+//! behind the `arbitrary` feature. The one of [Duration](crate::Duration)
+//! lives along with it.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Month, Weekday};
+
+/// Generates any month, from one byte of data.
+impl<'a> Arbitrary<'a> for Month {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&Month::ALL).copied()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u8::size_hint(depth)
+    }
+}
+
+/// Generates any weekday, from one byte of data.
+impl<'a> Arbitrary<'a> for Weekday {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&Weekday::ALL).copied()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u8::size_hint(depth)
+    }
+}
@@ -0,0 +1,155 @@
+//! Arithmetic on the proleptic Gregorian calendar, with dates given as a
+//! year, month and day.
+//!
+//! # Example
+//! ```
+#![doc = include_str!("../examples/calendar_period.rs")]
+//! ```
+
+use crate::{Month, Period};
+
+/// Returns the date `p` after the date `date`, adding the years and months of
+/// `p` first and then its days, as Go's `Time.AddDate` does. Days past the end
+/// of a month are normalized into the next one, so January 31 plus a month
+/// is March 2 or 3.
+///
+/// # Panics
+/// This function panics if the year is out of the range of `i32`.
+pub fn add_period(date: (i32, Month, u8), p: Period) -> (i32, Month, u8) {
+    let days = add_months(date, p.total_months()) + p.days as i64;
+
+    let (year, month, day) = civil_from_days(days);
+    let year = i32::try_from(year).expect("year out of range");
+    (year, month, day)
+}
+
+/// Returns the number of days from the date `from` to the date `to`,
+/// negative if `to` comes first.
+pub fn days_between(from: (i32, Month, u8), to: (i32, Month, u8)) -> i64 {
+    unix_days(to) - unix_days(from)
+}
+
+/// Returns the period from the date `from` to the date `to`, made of the
+/// largest number of whole months not going past `to`, carried into years,
+/// and the days remaining. All the components have the sign of the
+/// difference, and [add_period] of the period to `from` gives `to`.
+///
+/// # Panics
+/// This function panics if the years overflow, which only happens for dates
+/// over `i32::MAX` years apart.
+pub fn period_between(from: (i32, Month, u8), to: (i32, Month, u8)) -> Period {
+    let end = unix_days(to);
+    let mut months = (to.0 as i64 - from.0 as i64) * 12 + to.1 as i64 - from.1 as i64;
+
+    // Whole months may overshoot `to` when its day comes before the day of
+    // `from`, or when the day of `from` overflows the month reached.
+    if end >= unix_days(from) {
+        while months > 0 && add_months(from, months) > end {
+            months -= 1;
+        }
+    } else {
+        while months < 0 && add_months(from, months) < end {
+            months += 1;
+        }
+    }
+
+    let years = i32::try_from(months / 12).expect("period overflow");
+    let days = end - add_months(from, months);
+    Period::new(years, (months % 12) as i32, days as i32)
+}
+
+/// Returns the number of days since 1970-01-01 of the given date, using the
+/// algorithm from Howard Hinnant's "chrono-Compatible Low-Level Date
+/// Algorithms".
+pub(crate) fn days_from_civil(year: i32, month: Month, day: u8) -> i64 {
+    let m = month as i64;
+    let y = year as i64 - (m <= 2) as i64;
+
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+/// Returns the year, month and day of the date `days` days since 1970-01-01,
+/// the inverse of [days_from_civil].
+pub(crate) fn civil_from_days(days: i64) -> (i64, Month, u8) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+
+    let month = Month::ALL[m as usize - 1];
+    (yoe + era * 400 + (m <= 2) as i64, month, day)
+}
+
+/// Returns the month and day of Easter Sunday of `year` in the Gregorian
+/// calendar, using the anonymous Gregorian algorithm.
+pub(crate) fn easter(year: i32) -> (Month, u8) {
+    let y = year as i64;
+    let (a, b, c) = (y.rem_euclid(19), y.div_euclid(100), y.rem_euclid(100));
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let g = (8 * b + 13).div_euclid(25);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let l = (32 + 2 * e + 2 * (c / 4) - h - c % 4).rem_euclid(7);
+    let m = (a + 11 * h + 19 * l) / 433;
+
+    let month = (h + l - 7 * m + 90) / 25;
+    let day = (h + l - 7 * m + 33 * month + 19) % 32;
+    (Month::ALL[month as usize - 1], day as u8)
+}
+
+/// Returns the number of days in `year` before the first day of `month`.
+pub(crate) fn days_before(month: Month, year: i32) -> u16 {
+    const DAYS_BEFORE: [u16; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+    let n = DAYS_BEFORE[month as usize - 1];
+    if month > Month::February && is_leap(year) {
+        n + 1
+    } else {
+        n
+    }
+}
+
+/// Returns the number of days in `month` of `year`.
+pub(crate) fn days_in(month: Month, year: i32) -> u8 {
+    match month {
+        Month::February if is_leap(year) => 29,
+        Month::February => 28,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        _ => 31,
+    }
+}
+
+/// Reports whether `year` is a leap year.
+pub(crate) fn is_leap(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+// private APIs
+
+/// Returns the number of days since 1970-01-01 of the date `months` months
+/// after `date`, normalizing days past the end of the month reached.
+///
+/// # Panics
+/// This function panics if the year is out of the range of `i32`.
+fn add_months(date: (i32, Month, u8), months: i64) -> i64 {
+    let (year, month, day) = date;
+    let total = year as i64 * 12 + month as i64 - 1 + months;
+    let year = i32::try_from(total.div_euclid(12)).expect("year out of range");
+    let month = Month::ALL[total.rem_euclid(12) as usize];
+
+    days_from_civil(year, month, 1) + day as i64 - 1
+}
+
+/// Returns the number of days since 1970-01-01 of `date`.
+fn unix_days(date: (i32, Month, u8)) -> i64 {
+    let (year, month, day) = date;
+    days_from_civil(year, month, day)
+}
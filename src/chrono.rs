@@ -0,0 +1,89 @@
+//! Conversions between the types of this crate and the corresponding ones of
+//! chrono, behind the `chrono` feature.
+
+use crate::{Duration, DurationOutOfRangeError, Month, Weekday};
+
+impl From<Duration> for ::chrono::Duration {
+    fn from(value: Duration) -> Self {
+        ::chrono::Duration::nanoseconds(value.0)
+    }
+}
+
+impl TryFrom<::chrono::Duration> for Duration {
+    type Error = DurationOutOfRangeError;
+
+    /// Converts a chrono duration into a Duration, failing if it's out of the
+    /// range of Duration, which is narrower than chrono's.
+    fn try_from(value: ::chrono::Duration) -> Result<Self, Self::Error> {
+        value
+            .num_nanoseconds()
+            .map(Duration)
+            .ok_or(DurationOutOfRangeError)
+    }
+}
+
+impl From<Month> for ::chrono::Month {
+    fn from(value: Month) -> Self {
+        match value {
+            Month::January => ::chrono::Month::January,
+            Month::February => ::chrono::Month::February,
+            Month::March => ::chrono::Month::March,
+            Month::April => ::chrono::Month::April,
+            Month::May => ::chrono::Month::May,
+            Month::June => ::chrono::Month::June,
+            Month::July => ::chrono::Month::July,
+            Month::August => ::chrono::Month::August,
+            Month::September => ::chrono::Month::September,
+            Month::October => ::chrono::Month::October,
+            Month::November => ::chrono::Month::November,
+            Month::December => ::chrono::Month::December,
+        }
+    }
+}
+
+impl From<::chrono::Month> for Month {
+    fn from(value: ::chrono::Month) -> Self {
+        match value {
+            ::chrono::Month::January => Month::January,
+            ::chrono::Month::February => Month::February,
+            ::chrono::Month::March => Month::March,
+            ::chrono::Month::April => Month::April,
+            ::chrono::Month::May => Month::May,
+            ::chrono::Month::June => Month::June,
+            ::chrono::Month::July => Month::July,
+            ::chrono::Month::August => Month::August,
+            ::chrono::Month::September => Month::September,
+            ::chrono::Month::October => Month::October,
+            ::chrono::Month::November => Month::November,
+            ::chrono::Month::December => Month::December,
+        }
+    }
+}
+
+impl From<Weekday> for ::chrono::Weekday {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Sunday => ::chrono::Weekday::Sun,
+            Weekday::Monday => ::chrono::Weekday::Mon,
+            Weekday::Tuesday => ::chrono::Weekday::Tue,
+            Weekday::Wednesday => ::chrono::Weekday::Wed,
+            Weekday::Thursday => ::chrono::Weekday::Thu,
+            Weekday::Friday => ::chrono::Weekday::Fri,
+            Weekday::Saturday => ::chrono::Weekday::Sat,
+        }
+    }
+}
+
+impl From<::chrono::Weekday> for Weekday {
+    fn from(value: ::chrono::Weekday) -> Self {
+        match value {
+            ::chrono::Weekday::Sun => Weekday::Sunday,
+            ::chrono::Weekday::Mon => Weekday::Monday,
+            ::chrono::Weekday::Tue => Weekday::Tuesday,
+            ::chrono::Weekday::Wed => Weekday::Wednesday,
+            ::chrono::Weekday::Thu => Weekday::Thursday,
+            ::chrono::Weekday::Fri => Weekday::Friday,
+            ::chrono::Weekday::Sat => Weekday::Saturday,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! clap support for the types of this crate, behind the `clap` feature.
+
+use std::ffi::OsStr;
+
+use ::clap::builder::{TypedValueParser, ValueParserFactory};
+use ::clap::error::{Error, ErrorKind};
+use ::clap::{Arg, Command};
+
+use crate::{Duration, DurationParseError, DurationParser, UnitSet};
+
+/// A DurationValueParser parses command-line arguments such as
+/// `--timeout 30s` into a [Duration], with the units of a [DurationParser].
+///
+/// It's the value parser clap picks for [Duration], and invalid arguments are
+/// reported with the [DurationParseError](crate::DurationParseError) telling
+/// what's wrong, such as
+/// `invalid value '30x' for '--timeout <TIMEOUT>': unknown unit "x"`.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/duration_value_parser.rs")]
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DurationValueParser(DurationParser);
+
+impl DurationValueParser {
+    /// Returns a value parser accepting the units of
+    /// [parse_duration](crate::parse_duration).
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<DurationParser> for DurationValueParser {
+    fn from(value: DurationParser) -> Self {
+        Self(value)
+    }
+}
+
+impl TypedValueParser for DurationValueParser {
+    type Value = Duration;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let Some(value) = value.to_str() else {
+            return Err(Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd));
+        };
+
+        self.0.parse(value).map_err(|err| {
+            let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+            let mut message = format!("invalid value '{value}' for '{arg}': {err}");
+            // Errors list built-in units only, so list custom ones here.
+            if let DurationParseError::UnknownUnit {
+                accepted: UnitSet::Custom,
+                ..
+            } = err
+            {
+                let units = self.0.accepted_units();
+                if !units.is_empty() {
+                    message += &format!(" (valid units: {units})");
+                }
+            }
+            message.push('\n');
+            Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for Duration {
+    type Parser = DurationValueParser;
+
+    fn value_parser() -> Self::Parser {
+        DurationValueParser::new()
+    }
+}
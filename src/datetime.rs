@@ -0,0 +1,380 @@
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+use crate::{calendar, DateTimeParseError, Duration, Month, Period, SECOND};
+
+/// A DateTime is a civil date and time of day, such as 2024-03-10T02:30:00,
+/// without a time zone. It's what a wall clock and a calendar read, and
+/// names a different instant in each zone, or none or two across daylight
+/// saving time transitions.
+///
+/// Dates follow the proleptic Gregorian calendar, and times of day have no
+/// leap seconds.
+///
+/// Converting a DateTime to an instant given a time zone, with a policy for
+/// the local times skipped or repeated by daylight saving time transitions,
+/// waits for the `Time` and `Location` types, which don't exist yet.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/datetime.rs")]
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    year: i32,
+    month: Month,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl DateTime {
+    /// Returns the date and time of `day` of `month` of `year` at `hour`,
+    /// `minute`, `second` and `nanosecond`, or `None` if the month has no such
+    /// day or the time of day is out of range.
+    pub fn new(
+        year: i32,
+        month: Month,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Option<Self> {
+        Self::from_calendar_date(year, month, day)?.with_time(hour, minute, second, nanosecond)
+    }
+
+    /// Returns the midnight starting `day` of `month` of `year`, or `None` if
+    /// the month has no such day.
+    pub fn from_calendar_date(year: i32, month: Month, day: u8) -> Option<Self> {
+        (1..=calendar::days_in(month, year))
+            .contains(&day)
+            .then_some(Self {
+                year,
+                month,
+                day,
+                hour: 0,
+                minute: 0,
+                second: 0,
+                nanosecond: 0,
+            })
+    }
+
+    /// Returns the date and time `p` later, adding the period to the date as
+    /// [calendar::add_period] does and keeping the time of day.
+    ///
+    /// # Panics
+    /// This function panics if the year is out of the range of `i32`.
+    pub fn add_period(&self, p: Period) -> Self {
+        let (year, month, day) = calendar::add_period(self.to_calendar_date(), p);
+        Self {
+            year,
+            month,
+            day,
+            ..*self
+        }
+    }
+
+    /// Returns the date and time `d` later, or `None` if the year is out of
+    /// the range of `i32`.
+    pub fn checked_add(&self, d: Duration) -> Option<Self> {
+        let nanos = self.nanos_of_day() + d.0 % NANOS_PER_DAY;
+        let days = self
+            .unix_days()
+            .checked_add(d.0 / NANOS_PER_DAY + nanos.div_euclid(NANOS_PER_DAY))?;
+        Self::from_unix_days(days, nanos.rem_euclid(NANOS_PER_DAY))
+    }
+
+    /// Returns the date and time `d` earlier, or `None` if the year is out of
+    /// the range of `i32`.
+    pub fn checked_sub(&self, d: Duration) -> Option<Self> {
+        match d.0.checked_neg() {
+            Some(v) => self.checked_add(Duration(v)),
+            // Subtracting i64::MIN nanoseconds adds i64::MAX and one more.
+            None => self
+                .checked_add(Duration(i64::MAX))?
+                .checked_add(Duration(1)),
+        }
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the number of days from the date to the date of `other`,
+    /// negative if `other` comes first, as [calendar::days_between] does.
+    /// Times of day are ignored.
+    pub fn days_until(&self, other: &Self) -> i64 {
+        calendar::days_between(self.to_calendar_date(), other.to_calendar_date())
+    }
+
+    /// Returns the duration from `earlier` to the date and time, negative if
+    /// `earlier` comes after it, or `None` if it's out of the range of a
+    /// [Duration].
+    pub fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        let days = (self.unix_days() - earlier.unix_days()) as i128;
+        let nanos =
+            days * NANOS_PER_DAY as i128 + (self.nanos_of_day() - earlier.nanos_of_day()) as i128;
+        i64::try_from(nanos).ok().map(Duration)
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the month.
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the nanosecond, from 0 to 999999999.
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Returns the period from the date to the date of `other`, as
+    /// [calendar::period_between] does. Times of day are ignored.
+    ///
+    /// # Panics
+    /// This function panics if the years overflow, which only happens for dates
+    /// over `i32::MAX` years apart.
+    pub fn period_until(&self, other: &Self) -> Period {
+        calendar::period_between(self.to_calendar_date(), other.to_calendar_date())
+    }
+
+    /// Returns the second, from 0 to 59.
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the time of day as its hour, minute, second and nanosecond.
+    pub fn time_of_day(&self) -> (u8, u8, u8, u32) {
+        (self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Returns the date as its year, month and day, as taken by the functions
+    /// of [calendar] and [HolidayCalendar](crate::HolidayCalendar).
+    pub fn to_calendar_date(&self) -> (i32, Month, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    /// Returns the date and time with the time of day replaced, or `None` if
+    /// the hour, minute, second or nanosecond is out of range.
+    pub fn with_time(self, hour: u8, minute: u8, second: u8, nanosecond: u32) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60 && nanosecond < SECOND.0 as u32).then_some(Self {
+            hour,
+            minute,
+            second,
+            nanosecond,
+            ..self
+        })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn from_unix_days(days: i64, nanos_of_day: i64) -> Option<Self> {
+        let (year, month, day) = calendar::civil_from_days(days);
+        let secs = nanos_of_day / SECOND.0;
+        Some(Self {
+            year: i32::try_from(year).ok()?,
+            month,
+            day,
+            hour: (secs / 3600) as u8,
+            minute: (secs / 60 % 60) as u8,
+            second: (secs % 60) as u8,
+            nanosecond: (nanos_of_day % SECOND.0) as u32,
+        })
+    }
+
+    fn nanos_of_day(&self) -> i64 {
+        let secs = self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64;
+        secs * SECOND.0 + self.nanosecond as i64
+    }
+
+    fn unix_days(&self) -> i64 {
+        calendar::days_from_civil(self.year, self.month, self.day)
+    }
+}
+
+impl Display for DateTime {
+    /// Writes the date and time in the ISO 8601 extended form
+    /// "2024-03-10T02:30:00" to `f`, with the year padded to 4 digits and the
+    /// fraction of the second, if any, without trailing zeros.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year < 0 {
+            f.write_str("-")?;
+        }
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year.unsigned_abs(),
+            self.month as u8,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second
+        )?;
+
+        if self.nanosecond != 0 {
+            let mut frac = self.nanosecond;
+            let mut width = 9;
+            while frac % 10 == 0 {
+                frac /= 10;
+                width -= 1;
+            }
+            write!(f, ".{frac:0width$}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = DateTimeParseError;
+
+    /// Parses a date and time in the ISO 8601 extended form, such as
+    /// "2024-03-10T02:30:00" or "2024-03-10 02:30:00.5", or a date alone for
+    /// its midnight, such as "2024-03-10".
+    ///
+    /// The year has at least 4 digits and an optional sign, the time of day
+    /// is separated by 'T', 't' or a space, and the fraction of the second
+    /// has up to 9 digits. A zone offset isn't accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut p = Parser {
+            input: s.as_bytes(),
+            i: 0,
+        };
+
+        let neg = p.sign();
+        let start = p.i;
+        let year = p.number(4, usize::MAX)?;
+        let year = i64::try_from(year)
+            .ok()
+            .and_then(|v| i32::try_from(if neg { -v } else { v }).ok())
+            .ok_or(DateTimeParseError::OutOfRange { offset: start })?;
+        p.expect(b'-')?;
+
+        let start = p.i;
+        let month = p.number(2, 2)?;
+        let month = Month::try_from(month as u8)
+            .map_err(|_| DateTimeParseError::OutOfRange { offset: start })?;
+        p.expect(b'-')?;
+
+        let start = p.i;
+        let day = p.number(2, 2)? as u8;
+        let dt = Self::from_calendar_date(year, month, day)
+            .ok_or(DateTimeParseError::OutOfRange { offset: start })?;
+        if p.i == p.input.len() {
+            return Ok(dt);
+        }
+
+        match p.input[p.i] {
+            b'T' | b't' | b' ' => p.i += 1,
+            _ => return Err(DateTimeParseError::Invalid { offset: p.i }),
+        }
+
+        let mut fields = [0u8; 3];
+        let mut starts = [0usize; 3];
+        for (n, field) in fields.iter_mut().enumerate() {
+            if n > 0 {
+                p.expect(b':')?;
+            }
+            starts[n] = p.i;
+            *field = p.number(2, 2)? as u8;
+        }
+
+        let mut nanosecond = 0;
+        if p.i < p.input.len() && p.input[p.i] == b'.' {
+            p.i += 1;
+            let start = p.i;
+            nanosecond = p.number(1, 9)? as u32;
+            for _ in p.i - start..9 {
+                nanosecond *= 10;
+            }
+        }
+        if p.i != p.input.len() {
+            return Err(DateTimeParseError::Invalid { offset: p.i });
+        }
+
+        let [hour, minute, second] = fields;
+        let limits = [24, 60, 60];
+        if let Some(n) = (0..3).find(|&n| fields[n] >= limits[n]) {
+            return Err(DateTimeParseError::OutOfRange { offset: starts[n] });
+        }
+        Ok(dt
+            .with_time(hour, minute, second, nanosecond)
+            .expect("time of day in range"))
+    }
+}
+
+// private APIs
+
+const NANOS_PER_DAY: i64 = 86400 * SECOND.0;
+
+/// A cursor over the input of [DateTime::from_str].
+struct Parser<'a> {
+    input: &'a [u8],
+    i: usize,
+}
+
+impl Parser<'_> {
+    /// Skips the byte `c`, or fails if the input doesn't continue with it.
+    fn expect(&mut self, c: u8) -> Result<(), DateTimeParseError> {
+        if self.input.get(self.i) != Some(&c) {
+            return Err(DateTimeParseError::Invalid { offset: self.i });
+        }
+        self.i += 1;
+        Ok(())
+    }
+
+    /// Reads a number of `min` to `max` digits. Numbers too large for `u64`
+    /// are out of range.
+    fn number(&mut self, min: usize, max: usize) -> Result<u64, DateTimeParseError> {
+        let start = self.i;
+        let digits = self.input[start..]
+            .iter()
+            .take(max)
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if digits < min {
+            return Err(DateTimeParseError::Invalid {
+                offset: start + digits,
+            });
+        }
+
+        self.i += digits;
+        self.input[start..self.i]
+            .iter()
+            .try_fold(0u64, |v, c| {
+                v.checked_mul(10)?.checked_add((c - b'0') as u64)
+            })
+            .ok_or(DateTimeParseError::OutOfRange { offset: start })
+    }
+
+    /// Skips an optional sign, reporting whether it's negative.
+    fn sign(&mut self) -> bool {
+        match self.input.first() {
+            Some(b'-') => {
+                self.i += 1;
+                true
+            }
+            Some(b'+') => {
+                self.i += 1;
+                false
+            }
+            _ => false,
+        }
+    }
+}
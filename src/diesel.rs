@@ -0,0 +1,49 @@
+//! Diesel support for the types of this crate, behind the `diesel` feature.
+//!
+//! [Duration] maps to the `BigInt` SQL type of any backend as an integer
+//! nanosecond count, and to the PostgreSQL `Interval` type, precise to the
+//! microsecond. Days count as 24 hours, and intervals with months fail to
+//! decode.
+
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::pg::data_types::PgInterval;
+use ::diesel::pg::{Pg, PgValue};
+use ::diesel::serialize::{self, Output, ToSql};
+use ::diesel::sql_types::{BigInt, Interval};
+
+use crate::{interval, Duration};
+
+impl<DB> FromSql<BigInt, DB> for Duration
+where
+    DB: Backend,
+    i64: FromSql<BigInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        <i64 as FromSql<BigInt, DB>>::from_sql(bytes).map(Duration)
+    }
+}
+
+impl<DB> ToSql<BigInt, DB> for Duration
+where
+    DB: Backend,
+    i64: ToSql<BigInt, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <i64 as ToSql<BigInt, DB>>::to_sql(&self.0, out)
+    }
+}
+
+impl FromSql<Interval, Pg> for Duration {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let v: PgInterval = FromSql::<Interval, Pg>::from_sql(bytes)?;
+        interval::from_interval(v.months, v.days, v.microseconds)
+    }
+}
+
+impl ToSql<Interval, Pg> for Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let v = PgInterval::new(interval::to_microseconds(*self)?, 0, 0);
+        ToSql::<Interval, Pg>::to_sql(&v, &mut out.reborrow())
+    }
+}
@@ -0,0 +1,110 @@
+use super::{leading_int, offset_of, Duration, HOUR, MINUTE, POW10, SECOND};
+use crate::DurationParseError;
+
+impl Duration {
+    /// Parses a clock-style duration string in the form `[-][H:]MM:SS[.fff]`,
+    /// such as "01:30:05.250", "1:30:05" or "30:05", as used by ffmpeg,
+    /// spreadsheets, timesheets and subtitle files.
+    ///
+    /// Hours may have any number of digits, while minutes and seconds have one
+    /// or two digits and must be below 60. The seconds may carry a fraction
+    /// using either '.' or ',' as the decimal sign; digits beyond nanoseconds
+    /// are truncated.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_parse_clock.rs")]
+    /// ```
+    pub fn parse_clock<S>(s: S) -> Result<Self, DurationParseError>
+    where
+        S: AsRef<str>,
+    {
+        let input = s.as_ref().as_bytes();
+        let mut s = input;
+        let invalid = |s: &[u8]| DurationParseError::Invalid {
+            offset: offset_of(input, s),
+        };
+
+        let neg = match s.first() {
+            Some(b'-') => {
+                s = &s[1..];
+                true
+            }
+            Some(b'+') => {
+                s = &s[1..];
+                false
+            }
+            _ => false,
+        };
+
+        // Fields along with their number of digits and their input.
+        let mut fields = [(0u64, 0usize, s); 3];
+        let mut n = 0;
+        loop {
+            let digits = s.iter().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 || n == fields.len() {
+                return Err(invalid(s));
+            }
+
+            let (v, ss) = leading_int(s).ok_or(DurationParseError::Overflow {
+                offset: offset_of(input, s),
+            })?;
+            fields[n] = (v, digits, s);
+            n += 1;
+            s = ss;
+
+            match s.first() {
+                Some(b':') => s = &s[1..],
+                _ => break,
+            }
+        }
+
+        let (h, m, sec) = match n {
+            2 => ((0, 0, input), fields[0], fields[1]),
+            3 => (fields[0], fields[1], fields[2]),
+            _ => return Err(invalid(s)),
+        };
+        for field in [m, sec] {
+            if field.0 >= 60 || field.1 > 2 {
+                return Err(invalid(field.2));
+            }
+        }
+        let (h, m, sec) = (h.0, m.0, sec.0);
+
+        let mut frac = 0u64;
+        if let Some(b'.' | b',') = s.first() {
+            s = &s[1..];
+            let digits = s.iter().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 {
+                return Err(invalid(s));
+            }
+            for c in &s[..digits.min(9)] {
+                frac = frac * 10 + (c - b'0') as u64;
+            }
+            frac *= POW10[9 - digits.min(9)];
+            s = &s[digits..];
+        }
+
+        if !s.is_empty() {
+            return Err(invalid(s));
+        }
+
+        let d = h
+            .checked_mul(HOUR.0 as u64)
+            .and_then(|d| d.checked_add(m * MINUTE.0 as u64 + sec * SECOND.0 as u64 + frac))
+            .ok_or(DurationParseError::Overflow { offset: 0 })?;
+
+        if neg {
+            if d > (1 << 63) {
+                return Err(DurationParseError::Overflow { offset: 0 });
+            }
+            return Ok(Self((d as i64).wrapping_neg()));
+        }
+
+        if d > (i64::MAX as u64) {
+            return Err(DurationParseError::Overflow { offset: 0 });
+        }
+
+        Ok(Self(d as i64))
+    }
+}
@@ -0,0 +1,197 @@
+use alloc::string::{String, ToString};
+use core::str;
+
+use super::{fmt_frac, Duration, HOUR, MICROSECOND, MILLISECOND, MINUTE, NANOSECOND, SECOND};
+
+/// A DurationUnit specifies one of the units a [Duration] formats with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DurationUnit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+}
+
+impl DurationUnit {
+    /// Returns the [Duration] of one unit.
+    pub fn duration(&self) -> Duration {
+        match self {
+            DurationUnit::Nanosecond => NANOSECOND,
+            DurationUnit::Microsecond => MICROSECOND,
+            DurationUnit::Millisecond => MILLISECOND,
+            DurationUnit::Second => SECOND,
+            DurationUnit::Minute => MINUTE,
+            DurationUnit::Hour => HOUR,
+        }
+    }
+
+    /// Returns the suffix of the unit as used by [Display](std::fmt::Display)
+    /// of [Duration] ("ns", "µs", "ms", "s", "m", "h").
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            DurationUnit::Nanosecond => "ns",
+            DurationUnit::Microsecond => "µs",
+            DurationUnit::Millisecond => "ms",
+            DurationUnit::Second => "s",
+            DurationUnit::Minute => "m",
+            DurationUnit::Hour => "h",
+        }
+    }
+
+    fn nanos(&self) -> u64 {
+        self.duration().0 as u64
+    }
+}
+
+/// FormatOptions controls how [Duration::format_with] formats a duration.
+///
+/// The default options format like [Display](std::fmt::Display) of [Duration].
+#[derive(Clone, Debug)]
+pub struct FormatOptions {
+    largest: DurationUnit,
+    separator: String,
+    smallest: DurationUnit,
+}
+
+impl FormatOptions {
+    /// Returns the options formatting like [Display](std::fmt::Display) of
+    /// [Duration], i.e. with units from hours down to nanoseconds and no
+    /// separator between components.
+    pub fn new() -> Self {
+        Self {
+            largest: DurationUnit::Hour,
+            separator: String::new(),
+            smallest: DurationUnit::Nanosecond,
+        }
+    }
+
+    /// Sets the largest unit emitted. Larger amounts accumulate in this unit,
+    /// e.g. "150m0s" instead of "2h30m0s" for [DurationUnit::Minute].
+    /// A largest unit smaller than the smallest one is raised to it.
+    pub fn with_largest_unit(mut self, unit: DurationUnit) -> Self {
+        self.largest = unit;
+        self
+    }
+
+    /// Sets the separator inserted between components, e.g. "1h 2m 3s" for " "
+    /// or "1h, 2m, 3s" for ", ".
+    pub fn with_separator<S>(mut self, separator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the smallest unit emitted. The duration is rounded half away from
+    /// zero to a multiple of this unit before formatting, e.g. "1h15m31s"
+    /// instead of "1h15m30.918273645s" for [DurationUnit::Second].
+    pub fn with_smallest_unit(mut self, unit: DurationUnit) -> Self {
+        self.smallest = unit;
+        self
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Duration {
+    /// Returns a string representing the duration like [Display](std::fmt::Display),
+    /// but restricted to the range of units given by `opts`.
+    ///
+    /// Units from seconds up are emitted as separate components, with any
+    /// sub-second remainder as a fraction of the seconds. Durations less than
+    /// one second, or when the largest unit is smaller than a second, are
+    /// formatted as a single component with a fraction, using the largest unit
+    /// allowed that keeps the leading digit non-zero.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_format_with.rs")]
+    /// ```
+    pub fn format_with(&self, opts: &FormatOptions) -> String {
+        let smallest = opts.smallest;
+        let largest = opts.largest.max(smallest);
+
+        let m = smallest.nanos();
+        let u = self.0.unsigned_abs();
+        let u = match u % m {
+            r if r >= m - r => u - r + m,
+            r => u - r,
+        };
+
+        let mut out = String::new();
+
+        if u == 0 {
+            out.push('0');
+            out.push_str(smallest.max(DurationUnit::Second).suffix());
+            return out;
+        }
+
+        if self.0 < 0 {
+            out.push('-');
+        }
+
+        if largest < DurationUnit::Second || u < SECOND.0 as u64 {
+            let unit = [
+                DurationUnit::Millisecond,
+                DurationUnit::Microsecond,
+                DurationUnit::Nanosecond,
+            ]
+            .into_iter()
+            .find(|unit| *unit <= largest && u >= unit.nanos())
+            .unwrap_or(DurationUnit::Nanosecond);
+
+            push_component(&mut out, u, unit);
+            return out;
+        }
+
+        let units = [
+            DurationUnit::Hour,
+            DurationUnit::Minute,
+            DurationUnit::Second,
+        ]
+        .into_iter()
+        .filter(|unit| *unit <= largest && *unit >= smallest);
+
+        let mut r = u;
+        let mut started = false;
+        for unit in units {
+            let v = r / unit.nanos();
+            if v == 0 && !started && unit != smallest.max(DurationUnit::Second) {
+                continue;
+            }
+            if started {
+                out.push_str(&opts.separator);
+            }
+            started = true;
+
+            if unit == DurationUnit::Second {
+                push_component(&mut out, r, unit);
+            } else {
+                push_component(&mut out, v * unit.nanos(), unit);
+            }
+            r %= unit.nanos();
+        }
+
+        out
+    }
+}
+
+/// Appends `u` nanoseconds expressed in `unit` to `out`, with the fraction
+/// below `unit` omitting trailing zeros.
+fn push_component(out: &mut String, u: u64, unit: DurationUnit) {
+    let mut buf = [0u8; 32];
+
+    let prec = unit.nanos().ilog10() as i32;
+    let (w, _) = fmt_frac(&mut buf, u % unit.nanos(), prec);
+
+    out.push_str(&(u / unit.nanos()).to_string());
+    out.push_str(unsafe { str::from_utf8_unchecked(&buf[w..]) });
+    out.push_str(unit.suffix());
+}
@@ -0,0 +1,14 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::Duration;
+
+/// Generates any duration, from i64::MIN to i64::MAX nanoseconds.
+impl<'a> Arbitrary<'a> for Duration {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        i64::arbitrary(u).map(Duration)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        i64::size_hint(depth)
+    }
+}
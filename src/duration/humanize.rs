@@ -0,0 +1,292 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use super::{Duration, HOUR, MINUTE, SECOND};
+
+/// Units used by [Duration::humanize], largest first.
+const UNITS: [(Duration, Unit); 4] = [
+    (Duration(24 * HOUR.0), Unit::Day),
+    (HOUR, Unit::Hour),
+    (MINUTE, Unit::Minute),
+    (SECOND, Unit::Second),
+];
+
+/// A Locale specifies the language used by [Duration::humanize_in].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// Chinese (zh).
+    Chinese,
+    /// English (en).
+    English,
+    /// French (fr).
+    French,
+    /// German (de).
+    German,
+    /// Russian (ru).
+    Russian,
+    /// Spanish (es).
+    Spanish,
+}
+
+impl Duration {
+    /// Returns an approximate, human-friendly description of the magnitude of
+    /// `self`, such as "2 hours" or "about 3 days".
+    ///
+    /// The duration is expressed in the largest unit among days, hours,
+    /// minutes and seconds that it reaches, rounded half away from zero to a
+    /// whole count. Descriptions which had to be rounded start with "about".
+    /// Durations below a second are described as "less than a second". A day
+    /// is always 24 hours long.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_humanize.rs")]
+    /// ```
+    pub fn humanize(&self) -> String {
+        self.humanize_in(Locale::English)
+    }
+
+    /// Same as [Duration::humanize], but described in the language of `locale`.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_humanize_in.rs")]
+    /// ```
+    pub fn humanize_in(&self, locale: Locale) -> String {
+        describe(self.0.unsigned_abs(), locale, false)
+    }
+
+    /// Returns a human-friendly description of `self` relative to now, such as
+    /// "in 5 minutes" for positive durations and "5 minutes ago" for negative
+    /// ones. The zero duration is described as "now".
+    ///
+    /// See [Duration::humanize] for how the magnitude is described.
+    pub fn humanize_relative(&self) -> String {
+        self.humanize_relative_in(Locale::English)
+    }
+
+    /// Same as [Duration::humanize_relative], but described in the language of
+    /// `locale`.
+    pub fn humanize_relative_in(&self, locale: Locale) -> String {
+        if self.0 == 0 {
+            return locale.now().to_string();
+        }
+
+        let s = describe(self.0.unsigned_abs(), locale, true);
+        if self.0 > 0 {
+            locale.future(&s)
+        } else {
+            locale.past(&s)
+        }
+    }
+}
+
+impl Locale {
+    fn about(self, s: &str) -> String {
+        match self {
+            Locale::Chinese => format!("大约{s}"),
+            Locale::English => format!("about {s}"),
+            Locale::French => format!("environ {s}"),
+            Locale::German => format!("etwa {s}"),
+            Locale::Russian => format!("примерно {s}"),
+            Locale::Spanish => format!("alrededor de {s}"),
+        }
+    }
+
+    fn future(self, s: &str) -> String {
+        match self {
+            Locale::Chinese => format!("{s}后"),
+            Locale::English => format!("in {s}"),
+            Locale::French => format!("dans {s}"),
+            Locale::German => format!("in {s}"),
+            Locale::Russian => format!("через {s}"),
+            Locale::Spanish => format!("dentro de {s}"),
+        }
+    }
+
+    /// Describes durations below a second. `relative` selects the grammatical
+    /// case used after the words of [Locale::future] and [Locale::past].
+    fn less_than_a_second(self, relative: bool) -> &'static str {
+        match self {
+            Locale::Chinese => "不到1秒",
+            Locale::English => "less than a second",
+            Locale::French => "moins d'une seconde",
+            Locale::German if relative => "weniger als einer Sekunde",
+            Locale::German => "weniger als eine Sekunde",
+            Locale::Russian => "меньше секунды",
+            Locale::Spanish => "menos de un segundo",
+        }
+    }
+
+    fn now(self) -> &'static str {
+        match self {
+            Locale::Chinese => "现在",
+            Locale::English => "now",
+            Locale::French => "maintenant",
+            Locale::German => "jetzt",
+            Locale::Russian => "сейчас",
+            Locale::Spanish => "ahora",
+        }
+    }
+
+    fn past(self, s: &str) -> String {
+        match self {
+            Locale::Chinese => format!("{s}前"),
+            Locale::English => format!("{s} ago"),
+            Locale::French => format!("il y a {s}"),
+            Locale::German => format!("vor {s}"),
+            Locale::Russian => format!("{s} назад"),
+            Locale::Spanish => format!("hace {s}"),
+        }
+    }
+
+    fn plural(self, n: u64) -> Plural {
+        match self {
+            Locale::Chinese => Plural::Other,
+            Locale::English | Locale::German | Locale::Spanish if n == 1 => Plural::One,
+            Locale::French if n <= 1 => Plural::One,
+            Locale::Russian if n % 10 == 1 && n % 100 != 11 => Plural::One,
+            Locale::Russian if (2..=4).contains(&(n % 10)) && !(12..=14).contains(&(n % 100)) => {
+                Plural::Few
+            }
+            _ => Plural::Other,
+        }
+    }
+
+    /// Formats `n` of `unit`. `relative` selects the grammatical case used
+    /// after the words of [Locale::future] and [Locale::past].
+    fn quantity(self, n: u64, unit: Unit, relative: bool) -> String {
+        use Plural::*;
+        use Unit::*;
+
+        let plural = self.plural(n);
+        let name = match self {
+            Locale::Chinese => {
+                let name = match unit {
+                    Day => "天",
+                    Hour => "小时",
+                    Minute => "分钟",
+                    Second => "秒",
+                };
+                return format!("{n}{name}");
+            }
+            Locale::English => match (unit, plural) {
+                (Day, One) => "day",
+                (Day, _) => "days",
+                (Hour, One) => "hour",
+                (Hour, _) => "hours",
+                (Minute, One) => "minute",
+                (Minute, _) => "minutes",
+                (Second, One) => "second",
+                (Second, _) => "seconds",
+            },
+            Locale::French => match (unit, plural) {
+                (Day, One) => "jour",
+                (Day, _) => "jours",
+                (Hour, One) => "heure",
+                (Hour, _) => "heures",
+                (Minute, One) => "minute",
+                (Minute, _) => "minutes",
+                (Second, One) => "seconde",
+                (Second, _) => "secondes",
+            },
+            // Relative descriptions take the dative case.
+            Locale::German => match (unit, plural) {
+                (Day, One) => "Tag",
+                (Day, _) if relative => "Tagen",
+                (Day, _) => "Tage",
+                (Hour, One) => "Stunde",
+                (Hour, _) => "Stunden",
+                (Minute, One) => "Minute",
+                (Minute, _) => "Minuten",
+                (Second, One) => "Sekunde",
+                (Second, _) => "Sekunden",
+            },
+            // Relative descriptions take the accusative case.
+            Locale::Russian => match (unit, plural) {
+                (Day, One) => "день",
+                (Day, Few) => "дня",
+                (Day, Other) => "дней",
+                (Hour, One) => "час",
+                (Hour, Few) => "часа",
+                (Hour, Other) => "часов",
+                (Minute, One) if relative => "минуту",
+                (Minute, One) => "минута",
+                (Minute, Few) => "минуты",
+                (Minute, Other) => "минут",
+                (Second, One) if relative => "секунду",
+                (Second, One) => "секунда",
+                (Second, Few) => "секунды",
+                (Second, Other) => "секунд",
+            },
+            Locale::Spanish => match (unit, plural) {
+                (Day, One) => "día",
+                (Day, _) => "días",
+                (Hour, One) => "hora",
+                (Hour, _) => "horas",
+                (Minute, One) => "minuto",
+                (Minute, _) => "minutos",
+                (Second, One) => "segundo",
+                (Second, _) => "segundos",
+            },
+        };
+
+        format!("{n} {name}")
+    }
+}
+
+/// Plural categories, named after the CLDR ones.
+#[derive(Clone, Copy)]
+enum Plural {
+    One,
+    Few,
+    Other,
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// Describes the magnitude `u` in nanoseconds in the language of `locale`.
+fn describe(u: u64, locale: Locale, relative: bool) -> String {
+    if u == 0 {
+        return locale.quantity(0, Unit::Second, relative);
+    } else if u < SECOND.0 as u64 {
+        return locale.less_than_a_second(relative).to_string();
+    }
+
+    let mut i = UNITS
+        .iter()
+        .position(|(unit, _)| u >= unit.0 as u64)
+        .expect("at least a second");
+
+    let (mut n, exact) = div_round(u, UNITS[i].0 .0 as u64);
+    // Rounding up may reach the next larger unit, e.g. 59m50s is about 1 hour.
+    if i > 0 && n * UNITS[i].0 .0 as u64 == UNITS[i - 1].0 .0 as u64 {
+        i -= 1;
+        n = 1;
+    }
+
+    let s = locale.quantity(n, UNITS[i].1, relative);
+    if exact {
+        s
+    } else {
+        locale.about(&s)
+    }
+}
+
+/// Divides `v` by `m` rounding half up, and reports whether the division was
+/// exact.
+fn div_round(v: u64, m: u64) -> (u64, bool) {
+    let (q, r) = (v / m, v % m);
+    if r >= m - r {
+        (q + 1, false)
+    } else {
+        (q, r == 0)
+    }
+}
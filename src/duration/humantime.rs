@@ -0,0 +1,161 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use super::{Duration, DurationParser, HOUR, MICROSECOND, MILLISECOND, MINUTE, NANOSECOND, SECOND};
+use crate::{DurationParseError, UnitSet};
+
+/// Seconds of the years and months of humantime, which are 365.25 and 30.44
+/// days long.
+const YEAR_SECONDS: u64 = 31_557_600;
+const MONTH_SECONDS: u64 = 2_630_016;
+
+const DAY: u64 = 24 * HOUR.0 as u64;
+
+/// Units of [DurationParser::humantime].
+const HUMANTIME_UNITS: [(&str, u64); 35] = [
+    ("nanos", NANOSECOND.0 as u64),
+    ("nsec", NANOSECOND.0 as u64),
+    ("ns", NANOSECOND.0 as u64),
+    ("usec", MICROSECOND.0 as u64),
+    ("us", MICROSECOND.0 as u64),
+    ("millis", MILLISECOND.0 as u64),
+    ("msec", MILLISECOND.0 as u64),
+    ("ms", MILLISECOND.0 as u64),
+    ("seconds", SECOND.0 as u64),
+    ("second", SECOND.0 as u64),
+    ("secs", SECOND.0 as u64),
+    ("sec", SECOND.0 as u64),
+    ("s", SECOND.0 as u64),
+    ("minutes", MINUTE.0 as u64),
+    ("minute", MINUTE.0 as u64),
+    ("mins", MINUTE.0 as u64),
+    ("min", MINUTE.0 as u64),
+    ("m", MINUTE.0 as u64),
+    ("hours", HOUR.0 as u64),
+    ("hour", HOUR.0 as u64),
+    ("hrs", HOUR.0 as u64),
+    ("hr", HOUR.0 as u64),
+    ("h", HOUR.0 as u64),
+    ("days", DAY),
+    ("day", DAY),
+    ("d", DAY),
+    ("weeks", 7 * DAY),
+    ("week", 7 * DAY),
+    ("w", 7 * DAY),
+    ("months", MONTH_SECONDS * SECOND.0 as u64),
+    ("month", MONTH_SECONDS * SECOND.0 as u64),
+    ("M", MONTH_SECONDS * SECOND.0 as u64),
+    ("years", YEAR_SECONDS * SECOND.0 as u64),
+    ("year", YEAR_SECONDS * SECOND.0 as u64),
+    ("y", YEAR_SECONDS * SECOND.0 as u64),
+];
+
+static HUMANTIME_PARSER: DurationParser = DurationParser::humantime();
+
+impl DurationParser {
+    /// Returns a parser accepting the grammar of the humantime crate, such as
+    /// "2days 3hours" or "1h 30min", so that config files written for it keep
+    /// working.
+    ///
+    /// Whitespace is allowed between components, and the units are those of
+    /// humantime: "nsec", "usec" and "msec" with their short forms,
+    /// "s"/"sec"/"secs"/"second"/"seconds", "m"/"min"/"mins"/"minute"/"minutes",
+    /// "h"/"hr"/"hrs"/"hour"/"hours", "d"/"day"/"days", "w"/"week"/"weeks",
+    /// "M"/"month"/"months" of 30.44 days and "y"/"year"/"years" of 365.25
+    /// days. Unlike humantime, a sign and fractions are accepted too.
+    pub const fn humantime() -> Self {
+        Self::builtin(&HUMANTIME_UNITS, humantime_unit, UnitSet::Humantime, true)
+    }
+}
+
+impl Duration {
+    /// Parses a duration string of the humantime crate, such as "2days 3hours",
+    /// the way [DurationParser::humantime] does.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_humantime.rs")]
+    /// ```
+    pub fn parse_humantime<S>(s: S) -> Result<Self, DurationParseError>
+    where
+        S: AsRef<str>,
+    {
+        HUMANTIME_PARSER.parse(s)
+    }
+
+    /// Returns a string representing the duration like the `format_duration`
+    /// function of the humantime crate, such as "1month 2days 3h 4m 5s 6ms".
+    ///
+    /// Years, months and days are spelled out and pluralized, the other units
+    /// are abbreviated, and zero components are omitted with the zero duration
+    /// formatting as "0s". Negative durations are prefixed with '-', which
+    /// humantime lacks.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_humantime.rs")]
+    /// ```
+    pub fn to_humantime(&self) -> String {
+        let u = self.0.unsigned_abs();
+        if u == 0 {
+            return String::from("0s");
+        }
+
+        let secs = u / SECOND.0 as u64;
+        let nanos = u % SECOND.0 as u64;
+        let day_secs = secs % YEAR_SECONDS % MONTH_SECONDS;
+
+        let components = [
+            (secs / YEAR_SECONDS, "year"),
+            (secs % YEAR_SECONDS / MONTH_SECONDS, "month"),
+            (day_secs / 86400, "day"),
+            (day_secs % 86400 / 3600, "h"),
+            (day_secs % 3600 / 60, "m"),
+            (day_secs % 60, "s"),
+            (nanos / 1_000_000, "ms"),
+            (nanos / 1000 % 1000, "us"),
+            (nanos % 1000, "ns"),
+        ];
+
+        let mut out = String::new();
+        if self.0 < 0 {
+            out.push('-');
+        }
+        let mut started = false;
+        for (i, (v, unit)) in components.into_iter().enumerate() {
+            if v == 0 {
+                continue;
+            }
+            if started {
+                out.push(' ');
+            }
+            started = true;
+            // Writing to a String never fails.
+            let _ = write!(out, "{v}{unit}");
+            if i < 3 && v > 1 {
+                out.push('s');
+            }
+        }
+
+        out
+    }
+}
+
+/// Returns the nanoseconds of the unit `suffix` of [HUMANTIME_UNITS].
+fn humantime_unit(suffix: &[u8]) -> Option<u64> {
+    let v = match suffix {
+        b"nanos" | b"nsec" | b"ns" => NANOSECOND.0 as u64,
+        b"usec" | b"us" => MICROSECOND.0 as u64,
+        b"millis" | b"msec" | b"ms" => MILLISECOND.0 as u64,
+        b"seconds" | b"second" | b"secs" | b"sec" | b"s" => SECOND.0 as u64,
+        b"minutes" | b"minute" | b"mins" | b"min" | b"m" => MINUTE.0 as u64,
+        b"hours" | b"hour" | b"hrs" | b"hr" | b"h" => HOUR.0 as u64,
+        b"days" | b"day" | b"d" => DAY,
+        b"weeks" | b"week" | b"w" => 7 * DAY,
+        b"months" | b"month" | b"M" => MONTH_SECONDS * SECOND.0 as u64,
+        b"years" | b"year" | b"y" => YEAR_SECONDS * SECOND.0 as u64,
+        _ => return None,
+    };
+
+    Some(v)
+}
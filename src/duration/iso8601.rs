@@ -0,0 +1,222 @@
+use alloc::string::{String, ToString};
+use core::str;
+
+use super::{fmt_frac, fmt_int, leading_int, offset_of, Duration, HOUR, MINUTE, POW10, SECOND};
+use crate::DurationParseError;
+
+/// Nanoseconds of the ISO 8601 designators in the order they must appear.
+/// Days are taken as exactly 24 hours, following the convention of Java's
+/// `Duration` and XML Schema's `dayTimeDuration`.
+const DATE_DESIGNATORS: [(u8, u64); 2] =
+    [(b'W', 7 * 24 * HOUR.0 as u64), (b'D', 24 * HOUR.0 as u64)];
+const TIME_DESIGNATORS: [(u8, u64); 3] = [
+    (b'H', HOUR.0 as u64),
+    (b'M', MINUTE.0 as u64),
+    (b'S', SECOND.0 as u64),
+];
+
+impl Duration {
+    /// Parses an ISO 8601 duration string, such as "PT1H30M", "P2DT0.5S" or
+    /// "-PT10S".
+    ///
+    /// The accepted grammar is an optional sign, followed by "P", optional
+    /// weeks ("W") and days ("D"), and an optional time part starting with "T"
+    /// made of hours ("H"), minutes ("M") and seconds ("S"). Only seconds may
+    /// carry a fraction, using either '.' or ',' as the decimal sign. A day
+    /// is always 24 hours long. Years and months aren't accepted because they
+    /// don't have a fixed length.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_iso8601.rs")]
+    /// ```
+    pub fn parse_iso8601<S>(s: S) -> Result<Self, DurationParseError>
+    where
+        S: AsRef<str>,
+    {
+        let input = s.as_ref().as_bytes();
+        let mut s = input;
+
+        let neg = match s.first() {
+            Some(b'-') => {
+                s = &s[1..];
+                true
+            }
+            Some(b'+') => {
+                s = &s[1..];
+                false
+            }
+            _ => false,
+        };
+
+        s = match s.first() {
+            Some(b'P' | b'p') => &s[1..],
+            _ => {
+                return Err(DurationParseError::Invalid {
+                    offset: offset_of(input, s),
+                })
+            }
+        };
+
+        let mut d = 0u64;
+        let mut components = 0;
+
+        let (v, n, ss) = leading_components(input, s, &DATE_DESIGNATORS)?;
+        d += v;
+        components += n;
+        s = ss;
+
+        if let Some(b'T' | b't') = s.first() {
+            let (v, n, ss) = leading_components(input, &s[1..], &TIME_DESIGNATORS)?;
+            if n == 0 {
+                return Err(DurationParseError::Invalid {
+                    offset: offset_of(input, ss),
+                });
+            }
+            d = d
+                .checked_add(v)
+                .ok_or(DurationParseError::Overflow { offset: 0 })?;
+            components += n;
+            s = ss;
+        }
+
+        if !s.is_empty() || components == 0 {
+            return Err(DurationParseError::Invalid {
+                offset: offset_of(input, s),
+            });
+        }
+
+        if neg {
+            if d > (1 << 63) {
+                return Err(DurationParseError::Overflow { offset: 0 });
+            }
+            return Ok(Self((d as i64).wrapping_neg()));
+        }
+
+        if d > (i64::MAX as u64) {
+            return Err(DurationParseError::Overflow { offset: 0 });
+        }
+
+        Ok(Self(d as i64))
+    }
+
+    /// Returns a string representing the duration in the ISO 8601 form
+    /// `PT72H3M0.5S`. Zero units are omitted, and the zero duration formats
+    /// as `PT0S`. Hours are never carried into days, and negative durations
+    /// are prefixed with '-'.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_iso8601.rs")]
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        // Longest output is -PT2562047H47M16.854775808S
+        let mut buf = [0u8; 32];
+        let mut w = buf.len();
+
+        let u = self.0.unsigned_abs();
+        let frac = u % SECOND.0 as u64;
+        let secs = u / SECOND.0 as u64;
+
+        if frac != 0 || secs % 60 != 0 || u == 0 {
+            w -= 1;
+            buf[w] = b'S';
+            (w, _) = fmt_frac(&mut buf[..w], frac, 9);
+            w = fmt_int(&mut buf[..w], secs % 60);
+        }
+
+        for (v, designator) in [((secs / 60) % 60, b'M'), (secs / 3600, b'H')] {
+            if v == 0 {
+                continue;
+            }
+            w -= 1;
+            buf[w] = designator;
+            w = fmt_int(&mut buf[..w], v);
+        }
+
+        w -= 2;
+        buf[w..(w + 2)].copy_from_slice(b"PT");
+
+        if self.0 < 0 {
+            w -= 1;
+            buf[w] = b'-';
+        }
+
+        unsafe { str::from_utf8_unchecked(&buf[w..]) }.to_string()
+    }
+}
+
+/// Consumes a run of ISO 8601 components whose designators appear in
+/// `designators` order. Only the seconds component may carry a fraction.
+/// It returns the total nanoseconds, the number of components consumed and the
+/// remaining input. `s` is a subslice of `input`, against which errors report
+/// their offset.
+fn leading_components<'a>(
+    input: &[u8],
+    mut s: &'a [u8],
+    designators: &[(u8, u64)],
+) -> Result<(u64, usize, &'a [u8]), DurationParseError> {
+    let invalid = |s: &[u8]| DurationParseError::Invalid {
+        offset: offset_of(input, s),
+    };
+
+    let mut d = 0u64;
+    let mut n = 0;
+    let mut next = 0;
+
+    while let Some(c) = s.first() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+
+        let start = s;
+        let (v, ss) = leading_int(s).ok_or(DurationParseError::Overflow {
+            offset: offset_of(input, start),
+        })?;
+        s = ss;
+
+        let mut frac = None;
+        if let Some(b'.' | b',') = s.first() {
+            s = &s[1..];
+            let digits = s.iter().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 {
+                return Err(invalid(s));
+            }
+
+            // Digits beyond nanoseconds are truncated.
+            let mut f = 0u64;
+            for c in &s[..digits.min(9)] {
+                f = f * 10 + (c - b'0') as u64;
+            }
+            frac = Some(f * POW10[9 - digits.min(9)]);
+            s = &s[digits..];
+        }
+
+        let designator = s.first().map(|c| c.to_ascii_uppercase());
+        let i = match designators[next..]
+            .iter()
+            .position(|(v, _)| Some(*v) == designator)
+        {
+            Some(i) => next + i,
+            None => return Err(invalid(s)),
+        };
+        if frac.is_some() && designators[i].0 != b'S' {
+            return Err(invalid(s));
+        }
+        s = &s[1..];
+        next = i + 1;
+
+        let unit = designators[i].1;
+
+        d = v
+            .checked_mul(unit)
+            .and_then(|v| v.checked_add(frac.unwrap_or(0)))
+            .and_then(|v| v.checked_add(d))
+            .ok_or(DurationParseError::Overflow {
+                offset: offset_of(input, start),
+            })?;
+        n += 1;
+    }
+
+    Ok((d, n, s))
+}
@@ -0,0 +1,121 @@
+use alloc::format;
+use core::fmt::Display;
+use core::ops::{Add, Neg, Sub};
+use core::str;
+
+use super::{fmt_frac, pad, Duration, HOUR, SECOND};
+use crate::DurationOutOfRangeError;
+
+/// A LongDuration represents the elapsed time between two instants as an
+/// int128 nanosecond count, for spans exceeding the approximately 290 years
+/// that a [Duration] can represent.
+///
+/// Every [Duration] converts into a LongDuration losslessly, and a LongDuration
+/// converts back when it's within the range of [Duration].
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/long_duration.rs")]
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Eq, PartialOrd, Ord, Hash)]
+pub struct LongDuration(pub i128);
+
+impl LongDuration {
+    /// Returns the absolute value of `self`.
+    /// As a special case, i128::MIN is converted to i128::MAX.
+    pub fn abs(&self) -> Self {
+        Self(self.0.checked_abs().unwrap_or(i128::MAX))
+    }
+
+    /// Returns the duration as a floating point number of hours.
+    pub fn hours(&self) -> f64 {
+        let hour = self.0 / HOUR.0 as i128;
+        let nsec = self.0 % HOUR.0 as i128;
+
+        (hour as f64) + (nsec as f64) / (60.0 * 60.0 * 1e9)
+    }
+
+    /// Returns the duration as an integer nanosecond count.
+    pub fn nanoseconds(&self) -> i128 {
+        self.0
+    }
+
+    /// Returns the duration as a floating point number of seconds.
+    pub fn seconds(&self) -> f64 {
+        let s = self.0 / SECOND.0 as i128;
+        let ns = self.0 % SECOND.0 as i128;
+
+        (s as f64) + (ns as f64) / 1e9
+    }
+}
+
+impl Add for LongDuration {
+    type Output = LongDuration;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Display for LongDuration {
+    /// Writes a string representing the duration in the same form as
+    /// [Duration], such as "72h3m0.5s".
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Ok(d) = Duration::try_from(*self) {
+            return d.fmt(f);
+        }
+
+        let u = self.0.unsigned_abs();
+        let (secs, nsec) = (u / SECOND.0 as u128, (u % SECOND.0 as u128) as u64);
+
+        let mut buf = [0u8; 16];
+        let (w, _) = fmt_frac(&mut buf, nsec, 9);
+        let frac = unsafe { str::from_utf8_unchecked(&buf[w..]) };
+
+        // Beyond the range of Duration, hours are always present.
+        let (h, m, s) = (secs / 3600, (secs / 60) % 60, secs % 60);
+        let sign = if self.0 < 0 { "-" } else { "" };
+
+        pad(f, &format!("{sign}{h}h{m}m{s}{frac}s"))
+    }
+}
+
+impl Neg for LongDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.checked_neg().unwrap_or(i128::MIN))
+    }
+}
+
+impl Sub for LongDuration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl From<Duration> for LongDuration {
+    fn from(value: Duration) -> Self {
+        Self(value.0 as i128)
+    }
+}
+
+impl From<i128> for LongDuration {
+    fn from(value: i128) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<LongDuration> for Duration {
+    type Error = DurationOutOfRangeError;
+
+    /// Converts a LongDuration into a Duration, failing if it's out of the
+    /// range of Duration.
+    fn try_from(value: LongDuration) -> Result<Self, Self::Error> {
+        i64::try_from(value.0)
+            .map(Duration)
+            .map_err(|_| DurationOutOfRangeError)
+    }
+}
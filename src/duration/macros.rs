@@ -0,0 +1,192 @@
+use super::{scale_fraction, Duration, HOUR, MICROSECOND, MILLISECOND, MINUTE, NANOSECOND, SECOND};
+
+/// Parses a duration string literal at compile time, like
+/// [parse_duration](crate::parse_duration) does at runtime. Typos such as
+/// `duration!("1h30")` or `duration!("5sec")` fail to compile.
+///
+/// The macro can be used to initialize `const` items.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/duration_macro.rs")]
+/// ```
+///
+/// An invalid duration string is rejected at compile time:
+/// ```compile_fail
+/// const TIMEOUT: time::Duration = time::duration!("30 seconds");
+/// ```
+#[macro_export]
+macro_rules! duration {
+    ($s:literal) => {{
+        const D: $crate::Duration = $crate::Duration::const_parse($s);
+        D
+    }};
+}
+
+impl Duration {
+    /// Parses a duration string like [parse_duration](crate::parse_duration),
+    /// but as a const fn, so that `const` items can be initialized from
+    /// strings with the validation done at compile time.
+    ///
+    /// # Panics
+    /// This function panics if `s` isn't a valid duration, which fails the
+    /// build when evaluated in a const context. Use
+    /// [parse_duration](crate::parse_duration) to handle errors at runtime.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_const_parse.rs")]
+    /// ```
+    pub const fn const_parse(s: &str) -> Self {
+        match parse_duration_const(s) {
+            Ok(d) => d,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+/// Const version of [parse_duration](crate::parse_duration) backing the
+/// [duration!](crate::duration) macro. Errors are reported as messages since
+/// they can't be formatted at compile time.
+pub(super) const fn parse_duration_const(s: &str) -> Result<Duration, &'static str> {
+    const INVALID: &str = "invalid duration";
+    const OVERFLOW: &str = "duration out of range";
+
+    let s = s.as_bytes();
+    let mut i = 0;
+    let mut d = 0u64;
+
+    let neg = !s.is_empty() && s[0] == b'-';
+    if !s.is_empty() && (s[0] == b'-' || s[0] == b'+') {
+        i += 1;
+    }
+
+    if s.len() == i + 1 && s[i] == b'0' {
+        return Ok(Duration(0));
+    }
+    if s.len() == i {
+        return Err(INVALID);
+    }
+
+    while i < s.len() {
+        if !(s[i] == b'.' || s[i].is_ascii_digit()) {
+            return Err(INVALID);
+        }
+
+        // The next number, as leading_int does.
+        let start = i;
+        let mut v = 0u64;
+        while i < s.len() && s[i].is_ascii_digit() {
+            if v > (1 << 63) / 10 {
+                return Err(OVERFLOW);
+            }
+            v = v * 10 + (s[i] - b'0') as u64;
+            if v > (1 << 63) {
+                return Err(OVERFLOW);
+            }
+            i += 1;
+        }
+        let pre = i != start;
+
+        // Its fraction, as leading_fraction does.
+        let mut f = 0u64;
+        let mut digits = 0;
+        let mut post = false;
+        if i < s.len() && s[i] == b'.' {
+            i += 1;
+            let start = i;
+            let mut overflow = false;
+            while i < s.len() && s[i].is_ascii_digit() {
+                if !overflow && f > (i64::MAX as u64) / 10 {
+                    overflow = true;
+                }
+                if !overflow {
+                    let y = f * 10 + (s[i] - b'0') as u64;
+                    if y > i64::MAX as u64 {
+                        overflow = true;
+                    } else {
+                        f = y;
+                        digits += 1;
+                    }
+                }
+                i += 1;
+            }
+            post = i != start;
+        }
+
+        if !pre && !post {
+            return Err(INVALID);
+        }
+
+        // consume unit
+        let start = i;
+        while i < s.len() && !(s[i] == b'.' || s[i].is_ascii_digit()) {
+            i += 1;
+        }
+        if i == start {
+            return Err("missing unit in duration");
+        }
+
+        let unit = match unit_nanos(s, start, i) {
+            Some(unit) => unit,
+            None => return Err("unknown unit in duration"),
+        };
+        if v > (1 << 63) / unit {
+            return Err(OVERFLOW);
+        }
+
+        v *= unit;
+        if f > 0 {
+            v += scale_fraction(f, digits, unit);
+            if v > (1 << 63) {
+                return Err(OVERFLOW);
+            }
+        }
+        d = match d.checked_add(v) {
+            Some(d) if d <= (1 << 63) => d,
+            _ => return Err(OVERFLOW),
+        };
+    }
+
+    if neg {
+        return Ok(Duration((d as i64).wrapping_neg()));
+    }
+
+    if d > (i64::MAX as u64) {
+        return Err(OVERFLOW);
+    }
+
+    Ok(Duration(d as i64))
+}
+
+/// Returns the nanoseconds of the unit `s[start..end]` among those accepted
+/// by [parse_duration](crate::parse_duration).
+const fn unit_nanos(s: &[u8], start: usize, end: usize) -> Option<u64> {
+    const UNITS: [(&str, Duration); 8] = [
+        ("ns", NANOSECOND),
+        ("us", MICROSECOND),
+        ("µs", MICROSECOND), // \u{00b5}
+        ("μs", MICROSECOND), // \u{03bc}
+        ("ms", MILLISECOND),
+        ("s", SECOND),
+        ("m", MINUTE),
+        ("h", HOUR),
+    ];
+
+    let mut i = 0;
+    while i < UNITS.len() {
+        let (suffix, unit) = (UNITS[i].0.as_bytes(), UNITS[i].1);
+        if suffix.len() == end - start {
+            let mut j = 0;
+            while j < suffix.len() && suffix[j] == s[start + j] {
+                j += 1;
+            }
+            if j == suffix.len() {
+                return Some(unit.0 as u64);
+            }
+        }
+        i += 1;
+    }
+
+    None
+}
@@ -1,12 +1,46 @@
-use std::collections::HashMap;
-use std::fmt::Display;
-use std::ops::{Add, Div, Neg, Sub};
-use std::str;
-use std::{ops::Mul, str::FromStr};
-
-use lazy_static::lazy_static;
-
-use crate::DurationParseError;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{Alignment, Display, Write};
+use core::ops::{Add, Div, Neg, Sub};
+use core::str;
+use core::{ops::Mul, str::FromStr};
+
+use crate::{DurationFromFloatError, DurationParseError, NegativeDurationError};
+
+mod binary;
+mod clock;
+mod format;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "humanize")]
+mod humanize;
+mod humantime;
+mod iso8601;
+mod long;
+mod macros;
+mod parser;
+mod range;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+mod serde;
+mod stats;
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "rand")]
+mod uniform;
+
+pub use format::*;
+#[cfg(feature = "humanize")]
+pub use humanize::*;
+pub use long::*;
+pub use parser::*;
+pub use range::*;
+pub use stats::*;
+#[cfg(feature = "proptest")]
+pub use strategy::*;
+#[cfg(feature = "rand")]
+pub use uniform::*;
 
 /// Duration of a nanosecond. There is no definition for units of Day or larger
 /// to avoid confusion across daylight savings time zone transitions.
@@ -26,6 +60,18 @@ pub const MINUTE: Duration = Duration(60_000_000_000);
 /// Duration of an hour. There is no definition for units of Day or larger
 /// to avoid confusion across daylight savings time zone transitions.
 pub const HOUR: Duration = Duration(3_600_000_000_000);
+/// Duration of a day, defined as exactly 24 hours.
+///
+/// Not every calendar day lasts 24 hours: days spanning a daylight savings
+/// time zone transition are 23 or 25 hours long. Only use this constant where
+/// a fixed 24-hour span is meant.
+#[cfg(feature = "days")]
+pub const DAY: Duration = Duration(24 * HOUR.0);
+/// Duration of a week, defined as exactly 7 [DAY]s.
+///
+/// The same daylight savings caveat as [DAY] applies.
+#[cfg(feature = "days")]
+pub const WEEK: Duration = Duration(7 * DAY.0);
 
 /// A Duration represents the elapsed time between two instants
 /// as an int64 nanosecond count. The representation limits the
@@ -48,22 +94,218 @@ pub const HOUR: Duration = Duration(3_600_000_000_000);
 /// ```
 #[doc = include_str!("../../examples/duration_to_string.rs")]
 /// ```
-#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+///
+/// # Overflow
+/// The arithmetic operators (`+`, `-`, `*`) follow the overflow policy of
+/// integers: they panic on overflow in debug builds, and wrap around in
+/// release builds unless `overflow-checks` is enabled. Use
+/// [Duration::wrapping_add] and friends where wrapping around is intended.
+#[derive(Clone, Copy, PartialEq, Debug, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::BigInt),
+    diesel(sql_type = diesel::sql_types::Interval)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash))
+)]
 pub struct Duration(pub i64);
 
 impl Duration {
     /// Returns the absolute value of `self`.
     /// As a special case, i64::MIN is converted to i64::MAX.
-    pub fn abs(&self) -> Self {
-        if self.0 >= 0 {
-            *self
-        } else if self == &MIN_DURATION {
-            MAX_DURATION
-        } else {
-            Self(-self.0)
+    pub const fn abs(&self) -> Self {
+        match self.0.checked_abs() {
+            Some(v) => Self(v),
+            None => MAX_DURATION,
+        }
+    }
+
+    /// Returns the result of dividing `self` by `rhs`, rounding half away from
+    /// zero to the nearest nanosecond.
+    /// If the result exceeds the maximum (or minimum) value that can be stored
+    /// in a Duration, `div_f64` returns the maximum (or minimum) duration.
+    /// A NaN result yields the zero duration.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_div_f64.rs")]
+    /// ```
+    pub fn div_f64(&self, rhs: f64) -> Self {
+        from_f64_nanos(self.0 as f64 / rhs)
+    }
+
+    /// Creates a new Duration from the specified number of whole hours,
+    /// returning `None` if the result exceeds the range of a Duration.
+    pub const fn checked_from_hours(hours: i64) -> Option<Self> {
+        match hours.checked_mul(HOUR.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Creates a new Duration from the specified number of whole microseconds,
+    /// returning `None` if the result exceeds the range of a Duration.
+    pub const fn checked_from_micros(micros: i64) -> Option<Self> {
+        match micros.checked_mul(MICROSECOND.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Creates a new Duration from the specified number of whole milliseconds,
+    /// returning `None` if the result exceeds the range of a Duration.
+    pub const fn checked_from_millis(millis: i64) -> Option<Self> {
+        match millis.checked_mul(MILLISECOND.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Creates a new Duration from the specified number of whole minutes,
+    /// returning `None` if the result exceeds the range of a Duration.
+    pub const fn checked_from_minutes(minutes: i64) -> Option<Self> {
+        match minutes.checked_mul(MINUTE.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Creates a new Duration from the specified number of whole seconds,
+    /// returning `None` if the result exceeds the range of a Duration.
+    pub const fn checked_from_seconds(seconds: i64) -> Option<Self> {
+        match seconds.checked_mul(SECOND.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
         }
     }
 
+    /// Returns the ratio of `self` to `rhs` as a floating point number, such
+    /// as 0.25 for 15m of 1h. Dividing by the zero duration yields an infinity,
+    /// or NaN if `self` is zero too.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_ratio.rs")]
+    /// ```
+    pub fn div_duration_f64(&self, rhs: Self) -> f64 {
+        self.0 as f64 / rhs.0 as f64
+    }
+
+    /// Writes the duration formatted like [Display] into `w`, without the
+    /// allocation of [ToString::to_string](alloc::string::ToString::to_string),
+    /// such as when encoding log records. Use [Duration::write_to] for
+    /// [std::io::Write] sinks.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_format_into.rs")]
+    /// ```
+    pub fn format_into<W>(&self, w: &mut W) -> core::fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        let mut buf = [0u8; 32];
+        w.write_str(fmt_duration(&mut buf, *self, None))
+    }
+
+    /// Creates a new Duration from the specified number of whole hours.
+    ///
+    /// # Panics
+    /// This function panics if the result exceeds the range of a Duration.
+    /// Use [Duration::checked_from_hours] to handle it instead.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_const.rs")]
+    /// ```
+    pub const fn from_hours(hours: i64) -> Self {
+        match Self::checked_from_hours(hours) {
+            Some(d) => d,
+            None => panic!("overflow in Duration::from_hours"),
+        }
+    }
+
+    /// Creates a new Duration from the specified number of whole microseconds.
+    ///
+    /// # Panics
+    /// This function panics if the result exceeds the range of a Duration.
+    /// Use [Duration::checked_from_micros] to handle it instead.
+    pub const fn from_micros(micros: i64) -> Self {
+        match Self::checked_from_micros(micros) {
+            Some(d) => d,
+            None => panic!("overflow in Duration::from_micros"),
+        }
+    }
+
+    /// Creates a new Duration from the specified number of whole milliseconds.
+    ///
+    /// # Panics
+    /// This function panics if the result exceeds the range of a Duration.
+    /// Use [Duration::checked_from_millis] to handle it instead.
+    pub const fn from_millis(millis: i64) -> Self {
+        match Self::checked_from_millis(millis) {
+            Some(d) => d,
+            None => panic!("overflow in Duration::from_millis"),
+        }
+    }
+
+    /// Creates a new Duration from the specified number of milliseconds
+    /// represented as `f64`, rounding half away from zero to the nearest
+    /// nanosecond.
+    ///
+    /// # Errors
+    /// Returns an error if `millis` is NaN or out of the range of a Duration.
+    pub fn from_millis_f64(millis: f64) -> Result<Self, DurationFromFloatError> {
+        try_from_f64_nanos(millis * 1e6)
+    }
+
+    /// Creates a new Duration from the specified number of whole minutes.
+    ///
+    /// # Panics
+    /// This function panics if the result exceeds the range of a Duration.
+    /// Use [Duration::checked_from_minutes] to handle it instead.
+    pub const fn from_minutes(minutes: i64) -> Self {
+        match Self::checked_from_minutes(minutes) {
+            Some(d) => d,
+            None => panic!("overflow in Duration::from_minutes"),
+        }
+    }
+
+    /// Creates a new Duration from the specified number of nanoseconds.
+    pub const fn from_nanos(nanos: i64) -> Self {
+        Self(nanos)
+    }
+
+    /// Creates a new Duration from the specified number of whole seconds.
+    ///
+    /// # Panics
+    /// This function panics if the result exceeds the range of a Duration.
+    /// Use [Duration::checked_from_seconds] to handle it instead.
+    pub const fn from_seconds(seconds: i64) -> Self {
+        match Self::checked_from_seconds(seconds) {
+            Some(d) => d,
+            None => panic!("overflow in Duration::from_seconds"),
+        }
+    }
+
+    /// Creates a new Duration from the specified number of seconds represented
+    /// as `f64`, rounding half away from zero to the nearest nanosecond.
+    ///
+    /// # Errors
+    /// Returns an error if `secs` is NaN or out of the range of a Duration.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_from_secs_f64.rs")]
+    /// ```
+    pub fn from_secs_f64(secs: f64) -> Result<Self, DurationFromFloatError> {
+        try_from_f64_nanos(secs * 1e9)
+    }
+
     /// Returns the duration as a floating point number of hours.
     ///
     /// # Example
@@ -77,33 +319,66 @@ impl Duration {
         (hour as f64) + (nsec as f64) / (60.0 * 60.0 * 1e9)
     }
 
+    /// Returns true if `self` is negative.
+    pub const fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns true if `self` is positive.
+    pub const fn is_positive(&self) -> bool {
+        self.0 > 0
+    }
+
+    /// Returns true if `self` is the zero duration.
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
     /// Returns the duration as an integer microsecond count.
     ///
     /// # Example
     /// ```
     #[doc = include_str!("../../examples/duration_microseconds.rs")]
     /// ```
-    pub fn microseconds(&self) -> i64 {
+    pub const fn microseconds(&self) -> i64 {
         self.0 / 1000
     }
 
+    /// Returns the duration as an integer microsecond count, rounding half away
+    /// from zero rather than truncating like [Duration::microseconds].
+    pub const fn microseconds_rounded(&self) -> i64 {
+        div_round(self.0, MICROSECOND.0)
+    }
+
     /// Returns the duration as an integer millisecond count.
     ///
     /// # Example
     /// ```
     #[doc = include_str!("../../examples/duration_milliseconds.rs")]
     /// ```
-    pub fn milliseconds(&self) -> i64 {
+    pub const fn milliseconds(&self) -> i64 {
         self.0 / 1_000_000
     }
 
+    /// Returns the duration as an integer millisecond count, rounding half away
+    /// from zero rather than truncating like [Duration::milliseconds], which
+    /// would bias exported latencies downward.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_milliseconds_rounded.rs")]
+    /// ```
+    pub const fn milliseconds_rounded(&self) -> i64 {
+        div_round(self.0, MILLISECOND.0)
+    }
+
     /// Returns the duration as an integer nanosecond count.
     ///
     /// # Example
     /// ```
     #[doc = include_str!("../../examples/duration_nanoseconds.rs")]
     /// ```
-    pub fn nanoseconds(&self) -> i64 {
+    pub const fn nanoseconds(&self) -> i64 {
         self.0
     }
 
@@ -120,6 +395,82 @@ impl Duration {
         (m as f64) + (nsec as f64) / (60.0 * 1e9)
     }
 
+    /// Returns the result of multiplying `self` by the fraction `numer / denom`,
+    /// such as three quarters of a budget, rounding toward zero to the nearest
+    /// nanosecond. The intermediate product can't overflow. If the result
+    /// exceeds the maximum (or minimum) value that can be stored in a Duration,
+    /// `mul_ratio` returns the maximum (or minimum) duration.
+    ///
+    /// # Panics
+    /// This function panics if `denom` is zero.
+    pub fn mul_ratio(&self, numer: i64, denom: i64) -> Self {
+        assert!(denom != 0, "zero denominator in Duration::mul_ratio");
+
+        let v = self.0 as i128 * numer as i128 / denom as i128;
+        if v > i64::MAX as i128 {
+            MAX_DURATION
+        } else if v < i64::MIN as i128 {
+            MIN_DURATION
+        } else {
+            Self(v as i64)
+        }
+    }
+
+    /// Returns the result of multiplying `self` by `rhs`, rounding half away
+    /// from zero to the nearest nanosecond.
+    /// If the result exceeds the maximum (or minimum) value that can be stored
+    /// in a Duration, `mul_f64` returns the maximum (or minimum) duration.
+    /// A NaN result yields the zero duration.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_mul_f64.rs")]
+    /// ```
+    pub fn mul_f64(&self, rhs: f64) -> Self {
+        from_f64_nanos(self.0 as f64 * rhs)
+    }
+
+    /// Creates a new Duration from the specified number of whole seconds and
+    /// additional nanoseconds. `nanos` may be negative or exceed one second,
+    /// in which case it carries into the seconds.
+    ///
+    /// # Panics
+    /// This function panics if the total exceeds the range of a Duration.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_new.rs")]
+    /// ```
+    pub const fn new(secs: i64, nanos: i32) -> Self {
+        let d = match secs.checked_mul(SECOND.0) {
+            Some(v) => v.checked_add(nanos as i64),
+            None => None,
+        };
+
+        match d {
+            Some(v) => Self(v),
+            None => panic!("overflow in Duration::new"),
+        }
+    }
+
+    /// Parses a duration string like [parse_duration], but directly from bytes,
+    /// such as when reading a binary protocol, without requiring them to be
+    /// valid UTF-8 beforehand.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_parse_bytes.rs")]
+    /// ```
+    pub fn parse_bytes(s: &[u8]) -> Result<Self, DurationParseError> {
+        DEFAULT_PARSER.parse_bytes(s)
+    }
+
+    /// Returns `self` as a percentage of `total`, such as 25.0 for 15m of 1h.
+    /// It's a shorthand for `100.0 * self.div_duration_f64(total)`.
+    pub fn percent_of(&self, total: Self) -> f64 {
+        100.0 * self.div_duration_f64(total)
+    }
+
     /// Returns the result of rounding `self` to the nearest multiple of `m`.
     /// The rounding behavior for halfway values is to round away from zero.
     /// If the result exceeds the maximum (or minimum)
@@ -131,7 +482,7 @@ impl Duration {
     /// ```
     #[doc = include_str!("../../examples/duration_round.rs")]
     /// ```
-    pub fn round(&self, m: Self) -> Self {
+    pub const fn round(&self, m: Self) -> Self {
         let (d, m) = (self.0, m.0);
 
         if m <= 0 {
@@ -146,22 +497,63 @@ impl Duration {
                 return Self(d + r);
             }
 
-            if let Some(d1) = (d + r).checked_sub(m) {
-                return Self(d1);
-            }
-
-            return MIN_DURATION; // overflow
+            return match (d + r).checked_sub(m) {
+                Some(d1) => Self(d1),
+                None => MIN_DURATION, // overflow
+            };
         }
 
         if less_than_half(r, m) {
             return Self(d - r);
         }
 
-        if let Some(d1) = (d - r).checked_add(m) {
-            return Self(d1);
+        match (d - r).checked_add(m) {
+            Some(d1) => Self(d1),
+            None => MAX_DURATION,
+        }
+    }
+
+    /// Returns the result of rounding `self` to a multiple of `m` as directed by
+    /// `mode`. If the result exceeds the maximum (or minimum) value that can be
+    /// stored in a Duration, `round_with` returns the maximum (or minimum)
+    /// duration. If m <= 0, `round_with` returns `self` unchanged.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_round_with.rs")]
+    /// ```
+    pub fn round_with(&self, m: Self, mode: RoundingMode) -> Self {
+        if m.0 <= 0 {
+            return *self;
         }
 
-        MAX_DURATION
+        let (d, m) = (self.0 as i128, m.0 as i128);
+        let (q, r) = (d.div_euclid(m), d.rem_euclid(m));
+
+        let q = match mode {
+            _ if r == 0 => q,
+            RoundingMode::Ceil => q + 1,
+            RoundingMode::Floor => q,
+            RoundingMode::HalfAwayFromZero if 2 * r == m => {
+                if d < 0 {
+                    q
+                } else {
+                    q + 1
+                }
+            }
+            RoundingMode::HalfEven if 2 * r == m => q + (q & 1),
+            _ if 2 * r < m => q,
+            _ => q + 1,
+        };
+
+        let v = q * m;
+        if v > i64::MAX as i128 {
+            MAX_DURATION
+        } else if v < i64::MIN as i128 {
+            MIN_DURATION
+        } else {
+            Self(v as i64)
+        }
     }
 
     /// Returns the duration as a floating point number of seconds.
@@ -176,24 +568,97 @@ impl Duration {
         (s as f64) + (ns as f64) / 1e9
     }
 
+    /// Returns a number representing the sign of `self`: 0 if it's zero, 1 if
+    /// it's positive and -1 if it's negative.
+    pub const fn signum(&self) -> i64 {
+        self.0.signum()
+    }
+
     /// Returns a string representing the duration in the form `72h3m0.5s`.
     /// Leading zero units are omitted. As a special case, durations less than one
     /// second format use a smaller unit (milli-, micro-, or nanoseconds) to ensure
     /// that the leading digit is non-zero. The zero duration formats as 0s.
     #[deprecated(since = "0.1.0", note = "use `to_string` instead")]
     pub fn string(&self) -> String {
-        self.to_string()
+        format!("{self}")
+    }
+
+    /// Converts `self` into a [std::time::Duration], so it can be passed to APIs
+    /// such as [std::thread::sleep].
+    ///
+    /// # Errors
+    /// Returns [NegativeDurationError] if `self` is negative, which isn't
+    /// representable by [std::time::Duration]. Use [Duration::to_std_abs] to
+    /// discard the sign instead.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../../examples/duration_to_std.rs")]
+    /// ```
+    pub const fn to_std(&self) -> Result<core::time::Duration, NegativeDurationError> {
+        if self.0 < 0 {
+            return Err(NegativeDurationError);
+        }
+
+        Ok(core::time::Duration::from_nanos(self.0 as u64))
+    }
+
+    /// Converts the absolute value of `self` into a [std::time::Duration].
+    /// Unlike [Duration::abs], i64::MIN is converted exactly.
+    pub const fn to_std_abs(&self) -> core::time::Duration {
+        core::time::Duration::from_nanos(self.0.unsigned_abs())
     }
 
     /// Returns the result of rounding `self` toward zero to a multiple of `m`.
     /// If `m` <= 0, `truncate` returns `self` unchanged.
-    pub fn truncate(&self, m: Self) -> Self {
+    pub const fn truncate(&self, m: Self) -> Self {
         if m.0 <= 0 {
             *self
         } else {
             Self(self.0 - self.0 % m.0)
         }
     }
+
+    /// Returns `self + rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_add(&self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Returns `self * rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_mul(&self, rhs: i64) -> Self {
+        Self(self.0.wrapping_mul(rhs))
+    }
+
+    /// Returns `self - rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_sub(&self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Same as [Duration::format_into], but writes into a [std::io::Write]
+    /// sink, such as a buffered log file.
+    #[cfg(feature = "std")]
+    pub fn write_to<W>(&self, w: &mut W) -> std::io::Result<()>
+    where
+        W: std::io::Write + ?Sized,
+    {
+        let mut buf = [0u8; 32];
+        w.write_all(fmt_duration(&mut buf, *self, None).as_bytes())
+    }
+}
+
+/// A RoundingMode specifies how [Duration::round_with] rounds a duration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds toward positive infinity.
+    Ceil,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds to the nearest multiple, and halfway values away from zero.
+    /// This is the behavior of [Duration::round].
+    HalfAwayFromZero,
+    /// Rounds to the nearest multiple, and halfway values to the even multiple,
+    /// i.e. banker's rounding.
+    HalfEven,
 }
 
 impl Add for Duration {
@@ -210,87 +675,18 @@ impl Display for Duration {
     /// second format use a smaller unit (milli-, micro-, or nanoseconds) to ensure
     /// that the leading digit is non-zero. The zero duration formats as 0s.
     ///
+    /// The precision, if specified, limits the number of fractional digits of
+    /// the smallest unit, rounding half away from zero, e.g. `{:.3}` formats
+    /// "1h15m30.918273645s" as "1h15m30.918s". Width, fill and alignment are
+    /// honored as for strings.
+    ///
     /// # Example
     /// ```
     #[doc = include_str!("../../examples/duration_to_string.rs")]
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // Largest time is 2540400h10m10.000000000s
-        if self.0 == i64::MIN {
-            return f.pad("-2562047h47m16.854775808s");
-        }
-
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut buf = [0u8; 32];
-        let mut w = buf.len();
-
-        let neg = self.0 < 0;
-        let mut u = self.0.unsigned_abs();
-
-        if u < SECOND.0 as u64 {
-            // Special case: if duration is smaller than a second,
-            // use smaller units, like 1.2ms
-            w -= 1;
-            buf[w] = b's';
-            w -= 1;
-
-            let prec = if u == 0 {
-                return f.pad("0s");
-            } else if u < MICROSECOND.0 as u64 {
-                // print nanoseconds
-                buf[w] = b'n';
-                0
-            } else if u < MILLISECOND.0 as u64 {
-                // print microseconds
-                // U+00B5 'µ' micro sign == 0xC2 0xB5
-                // Need room for two bytes.
-                w -= 1;
-                buf[w..(w + 2)].copy_from_slice(b"\xc2\xb5");
-                3
-            } else {
-                // print milliseconds
-                buf[w] = b'm';
-                6
-            };
-
-            let (ww, uu) = fmt_frac(&mut buf[..w], u, prec);
-            w = ww;
-            u = uu;
-            w = fmt_int(&mut buf[..w], u);
-        } else {
-            w -= 1;
-            buf[w] = b's';
-
-            let (ww, uu) = fmt_frac(&mut buf[..w], u, 9);
-            w = ww;
-            u = uu;
-
-            w = fmt_int(&mut buf[..w], u % 60);
-            u /= 60;
-
-            // u is now integer minutes
-            if u > 0 {
-                w -= 1;
-                buf[w] = b'm';
-                w = fmt_int(&mut buf[..w], u % 60);
-                u /= 60;
-
-                // u is now integer hours
-                // Stop at hours because days can be different lengths.
-                if u > 0 {
-                    w -= 1;
-                    buf[w] = b'h';
-                    w = fmt_int(&mut buf[..w], u);
-                }
-            }
-        }
-
-        if neg {
-            w -= 1;
-            buf[w] = b'-';
-        }
-
-        let out = unsafe { str::from_utf8_unchecked(&buf[w..]) };
-        f.pad(out)
+        pad(f, fmt_duration(&mut buf, *self, f.precision()))
     }
 }
 
@@ -349,124 +745,31 @@ impl From<i64> for Duration {
     }
 }
 
-impl FromStr for Duration {
-    type Err = DurationParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut s = s.as_bytes();
-        let mut d = 0u64;
-
-        let neg = if s.is_empty() {
-            false
-        } else {
-            let c = s[0];
-            if c == b'-' || c == b'+' {
-                s = &s[1..];
-                c == b'-'
-            } else {
-                false
-            }
-        };
-
-        if s == b"0" {
-            return Ok(Duration(0));
-        }
-        if s == b"" {
-            return Err(DurationParseError::Invalid);
-        }
-
-        while !s.is_empty() {
-            let mut f = 0i64;
-            let mut scale = 0f64;
-
-            if !((s[0] == b'.') || ((b'0' <= s[0]) && (s[0] <= b'9'))) {
-                return Err(DurationParseError::Invalid);
-            }
-
-            let pl = s.len();
-            let mut v = {
-                let (vv, ss) = leading_int(s).map_err(|_| DurationParseError::Invalid)?;
-                s = ss;
-                vv
-            };
-            let pre = pl != s.len();
-
-            let post = if !s.is_empty() && (s[0] == b'.') {
-                s = &s[1..];
-                let pl = s.len();
-                {
-                    let (ff, scale_, ss) = leading_fraction(s);
-                    f = ff;
-                    scale = scale_;
-                    s = ss;
-                }
-                pl != s.len()
-            } else {
-                false
-            };
-
-            if !pre && !post {
-                return Err(DurationParseError::Invalid);
-            }
-
-            // consume unit
-            let mut i = 0;
-            loop {
-                if i >= s.len() {
-                    break;
-                }
-
-                match s[i] {
-                    b'.' | b'0'..=b'9' => break,
-                    _ => {}
-                }
-                i += 1;
-            }
-            if i == 0 {
-                return Err(DurationParseError::MissUnit);
-            }
-            let u = str::from_utf8(&s[..i]).expect("no UTF-8 unit");
-            s = &s[i..];
-
-            let unit = if let Some(v) = UNIT_MAP.get(u) {
-                *v
-            } else {
-                return Err(DurationParseError::UnknownUnit {
-                    unit: u.to_string(),
-                });
-            };
-            if v > (i64::MIN as u64) / unit {
-                // overflow
-                return Err(DurationParseError::Invalid);
-            }
-
-            v *= unit;
-            if f > 0 {
-                v += ((f as f64) * (unit as f64 / scale)) as u64;
-                if v > (i64::MIN as u64) {
-                    return Err(DurationParseError::Invalid);
-                }
-            }
-            d += v;
-            if d > (i64::MIN as u64) {
-                return Err(DurationParseError::Invalid);
-            }
+impl From<core::time::Duration> for Duration {
+    /// Converts a [std::time::Duration] into a Duration, saturating at the
+    /// maximum duration if it doesn't fit.
+    fn from(value: core::time::Duration) -> Self {
+        match i64::try_from(value.as_nanos()) {
+            Ok(v) => Self(v),
+            Err(_) => MAX_DURATION,
         }
+    }
+}
 
-        if neg {
-            let mut d = d as i64;
-            if d != i64::MIN {
-                d = -d;
-            }
+impl TryFrom<Duration> for core::time::Duration {
+    type Error = NegativeDurationError;
 
-            return Ok(Self(d));
-        }
+    /// Converts a Duration into a [std::time::Duration], failing if it's negative.
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        value.to_std()
+    }
+}
 
-        if d > (i64::MAX as u64) {
-            return Err(DurationParseError::Invalid);
-        }
+impl FromStr for Duration {
+    type Err = DurationParseError;
 
-        Ok(Self(d as i64))
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DEFAULT_PARSER.parse(s)
     }
 }
 
@@ -476,7 +779,13 @@ impl FromStr for Duration {
 /// such as "300ms", "-1.5h" or "2h45m".
 /// Valid time units are "ns", "us" (or "µs"), "ms", "s", "m", "h".
 ///
+/// Fractions are computed exactly and truncated to the nanosecond. This
+/// departs from Go's `time.ParseDuration`, which scales fractions through a
+/// float64 and may round them: "0.3333333333333333333h" parses to 1ns short
+/// of 20m here, but to exactly 20m in Go.
+///
 /// We can also use [str::parse] instead thanks to [FromStr] implementation of [Duration].
+/// Use [DurationParser] to accept a different set of units.
 ///
 /// # Example
 /// ```
@@ -489,83 +798,242 @@ where
     s.as_ref().parse()
 }
 
-lazy_static! {
-    pub(crate) static ref UNIT_MAP: HashMap<&'static str, u64> = {
-        let mut m = HashMap::new();
-
-        m.insert("ns", NANOSECOND.0 as u64);
-        m.insert("us", MICROSECOND.0 as u64);
-        m.insert("µs", MICROSECOND.0 as u64); // \u{00b5}
-        m.insert("μs", MICROSECOND.0 as u64); // \u{03bc}
-        m.insert("ms", MILLISECOND.0 as u64);
-        m.insert("s", SECOND.0 as u64);
-        m.insert("m", MINUTE.0 as u64);
-        m.insert("h", HOUR.0 as u64);
-
-        m
-    };
+/// Parses a duration string like [parse_duration], additionally accepting "d"
+/// for days and "w" for weeks, such as "7d" or "2w3d12h".
+///
+/// A day is always 24 hours long; see [DAY] for the caveat around daylight
+/// savings time zone transitions.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/parse_duration_extended.rs")]
+/// ```
+#[cfg(feature = "days")]
+pub fn parse_duration_extended<S>(s: S) -> Result<Duration, DurationParseError>
+where
+    S: AsRef<str>,
+{
+    EXTENDED_PARSER.parse(s)
 }
 
 // private APIs
-//const LOWER_HEX: &'static str = "0123456789abcdef";
+const LOWER_HEX: &[u8] = b"0123456789abcdef";
 const RUNE_SELF: char = 0x80 as char;
-//const RUNE_ERROR: char = '\u{FFFD}';
-
-const ERR_LEADING_INT: &str = "time: bad [0-9]*";
 
 const MAX_DURATION: Duration = Duration(i64::MAX);
 
 const MIN_DURATION: Duration = Duration(i64::MIN);
 
-/// Formats the fraction of v/10**prec (e.g., ".12345") into the
-/// tail of buf, omitting trailing zeros. It omits the decimal
-/// point too when the fraction is 0. It returns the index where the
-/// output bytes begin and the value v/10**prec.
-fn fmt_frac(buf: &mut [u8], v: u64, prec: i32) -> (usize, u64) {
+/// Powers of ten from 10^0 up to 10^19, the largest fitting into u64.
+const POW10: [u64; 20] = {
+    let mut table = [1u64; 20];
+    let mut i = 1;
+    while i < table.len() {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+    table
+};
+
+/// Divides `v` by the positive `m`, rounding half away from zero.
+const fn div_round(v: i64, m: i64) -> i64 {
+    let (q, r) = (v / m, v % m);
+    if less_than_half(r.abs(), m) {
+        q
+    } else {
+        q + r.signum()
+    }
+}
+
+/// Writes `d` formatted like [Display] with the precision `prec` at the end of
+/// `buf`, returning the written part.
+fn fmt_duration(buf: &mut [u8; 32], d: Duration, prec: Option<usize>) -> &str {
+    // Largest time is 2562047h47m17s, after rounding i64::MIN to seconds.
     let mut w = buf.len();
-    let mut print = false;
-    let mut v = v;
 
-    for _i in 0..prec {
-        let digit = v % 10;
-        print = print || (digit != 0);
-        if print {
+    let neg = d.0 < 0;
+    let mut u = d.0.unsigned_abs();
+    if let Some(prec) = prec {
+        u = round_frac(u, prec);
+    }
+
+    if u < SECOND.0 as u64 {
+        // Special case: if duration is smaller than a second,
+        // use smaller units, like 1.2ms
+        w -= 1;
+        buf[w] = b's';
+        w -= 1;
+
+        let prec = if u == 0 {
+            return "0s";
+        } else if u < MICROSECOND.0 as u64 {
+            // print nanoseconds
+            buf[w] = b'n';
+            0
+        } else if u < MILLISECOND.0 as u64 {
+            // print microseconds
+            // U+00B5 'µ' micro sign == 0xC2 0xB5
+            // Need room for two bytes.
+            w -= 1;
+            buf[w..(w + 2)].copy_from_slice(b"\xc2\xb5");
+            3
+        } else {
+            // print milliseconds
+            buf[w] = b'm';
+            6
+        };
+
+        let (ww, uu) = fmt_frac(&mut buf[..w], u, prec);
+        w = ww;
+        u = uu;
+        w = fmt_int(&mut buf[..w], u);
+    } else {
+        w -= 1;
+        buf[w] = b's';
+
+        let (ww, uu) = fmt_frac(&mut buf[..w], u, 9);
+        w = ww;
+        u = uu;
+
+        w = fmt_int(&mut buf[..w], u % 60);
+        u /= 60;
+
+        // u is now integer minutes
+        if u > 0 {
             w -= 1;
-            buf[w] = (digit as u8) + b'0';
+            buf[w] = b'm';
+            w = fmt_int(&mut buf[..w], u % 60);
+            u /= 60;
+
+            // u is now integer hours
+            // Stop at hours because days can be different lengths.
+            if u > 0 {
+                w -= 1;
+                buf[w] = b'h';
+                w = fmt_int(&mut buf[..w], u);
+            }
         }
-        v /= 10;
     }
-    if print {
+
+    if neg {
         w -= 1;
-        buf[w] = b'.';
+        buf[w] = b'-';
     }
 
-    (w, v)
+    unsafe { str::from_utf8_unchecked(&buf[w..]) }
+}
+
+/// Formats the fraction of v/10**prec (e.g., ".12345") into the
+/// tail of buf, omitting trailing zeros. It omits the decimal
+/// point too when the fraction is 0. It returns the index where the
+/// output bytes begin and the value v/10**prec.
+fn fmt_frac(buf: &mut [u8], v: u64, prec: i32) -> (usize, u64) {
+    let scale = POW10[prec as usize];
+    let (q, mut f) = (v / scale, v % scale);
+    if f == 0 {
+        return (buf.len(), q);
+    }
+
+    let mut digits = prec as usize;
+    while f % 10 == 0 {
+        f /= 10;
+        digits -= 1;
+    }
+
+    let mut w = fmt_digits(buf, f, digits);
+    w -= 1;
+    buf[w] = b'.';
+
+    (w, q)
 }
 
 fn fmt_int(buf: &mut [u8], v: u64) -> usize {
+    let mut digits = 1;
+    let mut x = v;
+    while x >= 10 {
+        x /= 10;
+        digits += 1;
+    }
+
+    fmt_digits(buf, v, digits)
+}
+
+/// Writes the `digits` lowest decimal digits of `v`, padded with leading
+/// zeros, into the tail of `buf` two at a time, returning the index where they
+/// begin.
+fn fmt_digits(buf: &mut [u8], v: u64, digits: usize) -> usize {
+    const PAIRS: &[u8; 200] = b"\
+        0001020304050607080910111213141516171819\
+        2021222324252627282930313233343536373839\
+        4041424344454647484950515253545556575859\
+        6061626364656667686970717273747576777879\
+        8081828384858687888990919293949596979899";
+
     let mut w = buf.len();
-    if v == 0 {
+    let mut v = v;
+    for _ in 0..(digits / 2) {
+        let i = (v % 100) as usize * 2;
+        v /= 100;
+        w -= 2;
+        buf[w..(w + 2)].copy_from_slice(&PAIRS[i..(i + 2)]);
+    }
+    if digits % 2 == 1 {
         w -= 1;
-        buf[w] = b'0';
-    } else {
-        let mut v = v;
-        while v > 0 {
-            w -= 1;
-            buf[w] = ((v % 10) as u8) + b'0';
-            v /= 10;
-        }
+        buf[w] = (v % 10) as u8 + b'0';
     }
 
     w
 }
 
-fn leading_fraction(s: &[u8]) -> (i64, f64, &[u8]) {
-    let mut i = s.len();
-    let mut scale = 1f64;
+/// Converts a floating point nanosecond count into a [Duration], rounding half
+/// away from zero and saturating at the bounds. NaN maps to zero.
+fn from_f64_nanos(v: f64) -> Duration {
+    // `as` saturates on overflow and maps NaN to 0.
+    Duration(round(v) as i64)
+}
+
+/// Rounds `v` half away from zero like [f64::round], which isn't available
+/// without std.
+fn round(v: f64) -> f64 {
+    // Such magnitudes, infinities and NaN are integral already.
+    const MAX: f64 = (1u64 << 52) as f64;
+    if !(-MAX < v && v < MAX) {
+        return v;
+    }
+
+    // The fraction of `v` is exact for such magnitudes.
+    let t = v as i64 as f64;
+    match v - t {
+        f if f >= 0.5 => t + 1.0,
+        f if f <= -0.5 => t - 1.0,
+        _ => t,
+    }
+}
+
+/// Consumes the leading [0-9]* of `s` as the digits of a fraction, returning
+/// the value of those fitting into i64, their number and the rest of `s`.
+/// Digits past the first overflowing one are consumed but ignored, as in Go.
+fn leading_fraction(s: &[u8]) -> (u64, usize, &[u8]) {
+    let mut s = s;
+    let mut digits = 0;
     let mut overflow = false;
     let mut x = 0i64;
 
+    // Runs of 8 digits are taken at once as long as they don't overflow, the
+    // digits overflowing being found one by one below.
+    while let Some(v) = leading_8_digits(s) {
+        match x
+            .checked_mul(100_000_000)
+            .and_then(|x| x.checked_add(v as i64))
+        {
+            Some(y) => x = y,
+            None => break,
+        }
+        digits += 8;
+        s = &s[8..];
+    }
+
+    let mut i = s.len();
     for (j, c) in s.iter().enumerate() {
         if !c.is_ascii_digit() {
             i = j;
@@ -580,21 +1048,54 @@ fn leading_fraction(s: &[u8]) -> (i64, f64, &[u8]) {
             continue;
         }
 
-        let y = x * 10 + ((c - b'0') as i64);
+        // Wraps around to negative on overflow, as in Go.
+        let y = (x * 10).wrapping_add((c - b'0') as i64);
         if y < 0 {
             overflow = true;
             continue;
         }
         x = y;
-        scale *= 10.0;
+        digits += 1;
     }
 
-    (x, scale, &s[i..])
+    (x as u64, digits, &s[i..])
 }
 
-fn leading_int(s: &[u8]) -> Result<(u64, &[u8]), String> {
-    let mut i = s.len();
+/// Returns the value of the 8 leading ASCII digits of `s`, or `None` if `s`
+/// doesn't start with 8 digits. The digits are checked and combined all at
+/// once within a u64, a technique known as SWAR (SIMD within a register).
+fn leading_8_digits(s: &[u8]) -> Option<u64> {
+    // The first digit lands in the lowest byte.
+    let chunk = u64::from_le_bytes(s.get(..8)?.try_into().unwrap());
+
+    // Bytes below '0' wrap around to 0x80 or more when subtracting '0', and
+    // bytes above '9' reach 0x80 or more when adding 0x46.
+    let v = chunk.wrapping_sub(0x3030_3030_3030_3030);
+    if (v | chunk.wrapping_add(0x4646_4646_4646_4646)) & 0x8080_8080_8080_8080 != 0 {
+        return None;
+    }
+
+    // Combines adjacent digits into 2-digit numbers, then those into 4-digit
+    // ones, then those into the 8-digit value.
+    let v = (v * 10 + (v >> 8)) & 0x00ff_00ff_00ff_00ff;
+    let v = (v * 100 + (v >> 16)) & 0x0000_ffff_0000_ffff;
+    Some((v.wrapping_mul(10_000) + (v >> 32)) & 0xffff_ffff)
+}
+
+/// Consumes the leading [0-9]* of `s`, returning its value and the rest of
+/// `s`, or `None` if the value exceeds 1<<63.
+fn leading_int(s: &[u8]) -> Option<(u64, &[u8])> {
+    let mut s = s;
     let mut x = 0u64;
+    while let Some(v) = leading_8_digits(s) {
+        x = x
+            .checked_mul(100_000_000)
+            .and_then(|x| x.checked_add(v))
+            .filter(|x| *x <= (1 << 63))?;
+        s = &s[8..];
+    }
+
+    let mut i = s.len();
     for (j, c) in s.iter().enumerate() {
         if !c.is_ascii_digit() {
             i = j;
@@ -603,23 +1104,120 @@ fn leading_int(s: &[u8]) -> Result<(u64, &[u8]), String> {
 
         if x > (1 << 63) / 10 {
             // overflow
-            return Err(ERR_LEADING_INT.to_string());
+            return None;
         }
 
         x = x * 10 + ((c - b'0') as u64);
         if x > (1 << 63) {
             // overflow
-            return Err(ERR_LEADING_INT.to_string());
+            return None;
         }
     }
 
-    Ok((x, &s[i..]))
+    Some((x, &s[i..]))
+}
+
+/// Returns the nanoseconds of the fraction `f / 10^digits` of `unit`,
+/// truncated toward zero. The product is computed exactly in u128, as it's
+/// less than 10^38 for `f` below 2^63.
+const fn scale_fraction(f: u64, digits: usize, unit: u64) -> u64 {
+    let v = f as u128 * unit as u128;
+    if digits < POW10.len() {
+        return (v / POW10[digits] as u128) as u64;
+    }
+    if digits > 38 {
+        return 0;
+    }
+
+    // Dividing twice truncates the same as dividing by the product.
+    (v / POW10[19] as u128 / POW10[digits - 19] as u128) as u64
+}
+
+/// Returns the byte offset of `s` within `input`, of which it must be a
+/// subslice.
+pub(crate) fn offset_of(input: &[u8], s: &[u8]) -> usize {
+    s.as_ptr() as usize - input.as_ptr() as usize
+}
+
+/// Writes `s` into `f` honoring its width, fill and alignment like
+/// [Formatter::pad](std::fmt::Formatter::pad), except that the precision
+/// doesn't truncate `s`.
+fn pad(f: &mut core::fmt::Formatter<'_>, s: &str) -> core::fmt::Result {
+    // Without a width there's nothing to pad, so skip the overhead of
+    // Formatter::pad in the common `{}` case.
+    if f.width().is_none() {
+        return f.write_str(s);
+    }
+    if f.precision().is_none() {
+        return f.pad(s);
+    }
+
+    let n = s.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > n => width - n,
+        _ => return f.write_str(s),
+    };
+
+    let (pre, post) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        _ => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
 }
 
-fn less_than_half(x: i64, y: i64) -> bool {
+const fn less_than_half(x: i64, y: i64) -> bool {
     ((x as u64) << 1) < (y as u64)
 }
 
+/// Rounds the nanosecond count `u` half up so that the smallest unit used by
+/// [Display] for it has at most `prec` fractional digits.
+fn round_frac(u: u64, prec: usize) -> u64 {
+    let digits = if u < MICROSECOND.0 as u64 {
+        0
+    } else if u < MILLISECOND.0 as u64 {
+        3
+    } else if u < SECOND.0 as u64 {
+        6
+    } else {
+        9
+    };
+
+    if prec >= digits {
+        return u;
+    }
+
+    let m = POW10[digits - prec];
+    (u + m / 2) / m * m
+}
+
+/// Converts a floating point nanosecond count into a [Duration], rounding half
+/// away from zero. Unlike [from_f64_nanos], NaN and out-of-range values are
+/// reported as errors.
+fn try_from_f64_nanos(v: f64) -> Result<Duration, DurationFromFloatError> {
+    if v.is_nan() {
+        return Err(DurationFromFloatError::NaN);
+    }
+
+    let v = round(v);
+    // i64::MIN is exactly representable as f64 but i64::MAX is not.
+    if (v < i64::MIN as f64) || (v >= -(i64::MIN as f64)) {
+        return Err(DurationFromFloatError::Overflow);
+    }
+
+    Ok(Duration(v as i64))
+}
+
 pub(crate) fn quote<S>(s: S) -> String
 where
     S: AsRef<str>,
@@ -646,5 +1244,38 @@ where
     buf
 }
 
+/// Quotes `s` as Go's `quote` in the time package does, escaping every byte of
+/// the UTF-8 encoding of non-ASCII characters as `\xNN`.
+pub(crate) fn go_quote<S>(s: S) -> String
+where
+    S: AsRef<str>,
+{
+    let s = s.as_ref();
+    let mut buf = String::with_capacity(s.len() + 2);
+
+    buf.push('"');
+    for c in s.chars() {
+        if (c < RUNE_SELF) && (c >= ' ') {
+            match c {
+                '"' | '\\' => buf.push('\\'),
+                _ => {}
+            }
+            buf.push(c);
+            continue;
+        }
+
+        let mut b = [0u8; 4];
+        for v in c.encode_utf8(&mut b).bytes() {
+            buf.push_str("\\x");
+            buf.push(LOWER_HEX[(v >> 4) as usize] as char);
+            buf.push(LOWER_HEX[(v & 0xf) as usize] as char);
+        }
+    }
+
+    buf.push('"');
+
+    buf
+}
+
 #[cfg(test)]
 mod tests;
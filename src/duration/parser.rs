@@ -0,0 +1,485 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::str;
+
+use super::{
+    leading_fraction, leading_int, offset_of, scale_fraction, Duration, HOUR, MICROSECOND,
+    MILLISECOND, MINUTE, NANOSECOND, SECOND,
+};
+use crate::{DurationParseError, UnitSet, UnitSuffix};
+
+/// A DurationParser parses duration strings with a configurable set of unit
+/// suffixes.
+///
+/// [DurationParser::new] starts with the units accepted by [parse_duration](crate::parse_duration),
+/// which can then be extended or trimmed to tailor the accepted grammar.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/duration_parser.rs")]
+/// ```
+#[derive(Clone, Debug)]
+pub struct DurationParser {
+    units: Units,
+    whitespace: bool,
+}
+
+/// The units of a [DurationParser]. Built-in sets of units are looked up with
+/// a `match` until they get customized, so that the parsers behind
+/// [parse_duration](crate::parse_duration) need neither allocation nor lazy
+/// initialization.
+#[derive(Clone, Debug)]
+enum Units {
+    /// Suffixes along with their nanoseconds, the function looking them up,
+    /// and the set they make up.
+    Builtin(
+        &'static [(&'static str, u64)],
+        fn(&[u8]) -> Option<u64>,
+        UnitSet,
+    ),
+    Custom(BTreeMap<String, u64>),
+}
+
+/// Units of [DurationParser::new], from the smallest.
+const DEFAULT_UNITS: [(&str, u64); 8] = [
+    ("ns", NANOSECOND.0 as u64),
+    ("us", MICROSECOND.0 as u64),
+    ("µs", MICROSECOND.0 as u64), // \u{00b5}
+    ("μs", MICROSECOND.0 as u64), // \u{03bc}
+    ("ms", MILLISECOND.0 as u64),
+    ("s", SECOND.0 as u64),
+    ("m", MINUTE.0 as u64),
+    ("h", HOUR.0 as u64),
+];
+
+/// Units of [parse_duration_extended](crate::parse_duration_extended).
+#[cfg(feature = "days")]
+const EXTENDED_UNITS: [(&str, u64); 10] = [
+    DEFAULT_UNITS[0],
+    DEFAULT_UNITS[1],
+    DEFAULT_UNITS[2],
+    DEFAULT_UNITS[3],
+    DEFAULT_UNITS[4],
+    DEFAULT_UNITS[5],
+    DEFAULT_UNITS[6],
+    DEFAULT_UNITS[7],
+    ("d", super::DAY.0 as u64),
+    ("w", super::WEEK.0 as u64),
+];
+
+pub(crate) static DEFAULT_PARSER: DurationParser = DurationParser::new();
+
+#[cfg(feature = "days")]
+pub(crate) static EXTENDED_PARSER: DurationParser =
+    DurationParser::builtin(&EXTENDED_UNITS, extended_unit, UnitSet::Extended, false);
+
+impl DurationParser {
+    /// Returns the registered suffixes ordered by their units, then by
+    /// themselves, with the suffixes of equal units separated by '/', such as
+    /// "ns, us/µs/μs, ms, s, m, h".
+    pub fn accepted_units(&self) -> String {
+        let mut list = String::new();
+        let mut last: Option<(u64, &str)> = None;
+        loop {
+            let next = self
+                .units
+                .iter()
+                .map(|(k, v)| (v, k))
+                .filter(|v| last.map_or(true, |last| *v > last))
+                .min();
+            let Some((unit, suffix)) = next else {
+                return list;
+            };
+
+            match last {
+                None => {}
+                Some((v, _)) if v == unit => list.push('/'),
+                Some(_) => list.push_str(", "),
+            }
+            list.push_str(suffix);
+            last = next;
+        }
+    }
+
+    /// Returns a parser accepting the units "ns", "us" (or "µs"), "ms", "s",
+    /// "m" and "h".
+    pub const fn new() -> Self {
+        Self::builtin(&DEFAULT_UNITS, default_unit, UnitSet::Default, false)
+    }
+
+    /// Sets whether whitespace is allowed around the sign, numbers and units,
+    /// such as in "1h 30m" or " 2 h ". It's disallowed by default.
+    pub fn allow_whitespace(mut self, allow: bool) -> Self {
+        self.whitespace = allow;
+        self
+    }
+
+    /// Returns a parser accepting no unit at all.
+    pub const fn empty() -> Self {
+        Self {
+            units: Units::Custom(BTreeMap::new()),
+            whitespace: false,
+        }
+    }
+
+    /// Parses a duration string, which is a possibly signed sequence of
+    /// decimal numbers, each with optional fraction and one of the registered
+    /// unit suffixes.
+    pub fn parse<S>(&self, s: S) -> Result<Duration, DurationParseError>
+    where
+        S: AsRef<str>,
+    {
+        self.parse_bytes(s.as_ref().as_bytes())
+    }
+
+    /// Same as [DurationParser::parse], but parses directly from bytes without
+    /// requiring them to be valid UTF-8 beforehand.
+    pub fn parse_bytes(&self, input: &[u8]) -> Result<Duration, DurationParseError> {
+        let mut s = input;
+        let mut d = 0u64;
+
+        if self.whitespace {
+            s = trim_whitespace(s);
+        }
+
+        let neg = if s.is_empty() {
+            false
+        } else {
+            let c = s[0];
+            if c == b'-' || c == b'+' {
+                s = &s[1..];
+                c == b'-'
+            } else {
+                false
+            }
+        };
+
+        if self.whitespace {
+            s = trim_whitespace(s);
+        }
+
+        if s == b"0" {
+            return Ok(Duration(0));
+        }
+        if s == b"" {
+            return Err(DurationParseError::Invalid {
+                offset: offset_of(input, s),
+            });
+        }
+
+        while !s.is_empty() {
+            let mut f = 0u64;
+            let mut digits = 0;
+
+            // Errors of the component point at its start.
+            let start = offset_of(input, s);
+
+            if !((s[0] == b'.') || ((b'0' <= s[0]) && (s[0] <= b'9'))) {
+                return Err(DurationParseError::Invalid { offset: start });
+            }
+
+            let pl = s.len();
+            let mut v = {
+                let (vv, ss) =
+                    leading_int(s).ok_or(DurationParseError::Overflow { offset: start })?;
+                s = ss;
+                vv
+            };
+            let pre = pl != s.len();
+
+            let post = if !s.is_empty() && (s[0] == b'.') {
+                s = &s[1..];
+                let pl = s.len();
+                {
+                    let (ff, digits_, ss) = leading_fraction(s);
+                    f = ff;
+                    digits = digits_;
+                    s = ss;
+                }
+                pl != s.len()
+            } else {
+                false
+            };
+
+            if !pre && !post {
+                return Err(DurationParseError::Invalid { offset: start });
+            }
+
+            if self.whitespace {
+                s = trim_whitespace(s);
+            }
+
+            // consume unit
+            let mut i = 0;
+            loop {
+                if i >= s.len() {
+                    break;
+                }
+
+                match s[i] {
+                    b'.' | b'0'..=b'9' => break,
+                    c if self.whitespace && c.is_ascii_whitespace() => break,
+                    _ => {}
+                }
+                i += 1;
+            }
+            if i == 0 {
+                return Err(DurationParseError::MissUnit {
+                    offset: offset_of(input, s),
+                });
+            }
+            let (u, offset) = (&s[..i], offset_of(input, s));
+            s = &s[i..];
+            if self.whitespace {
+                s = trim_whitespace(s);
+            }
+
+            let unit = match self.units.get(u) {
+                Some(v) => v,
+                None => {
+                    let unit = UnitSuffix::new(u);
+                    let suggestion = self.suggest(unit.as_str());
+                    return Err(DurationParseError::UnknownUnit {
+                        unit,
+                        offset,
+                        len: u.len(),
+                        suggestion: suggestion.map(|v| UnitSuffix::new(v.as_bytes())),
+                        accepted: self.units.set(),
+                    });
+                }
+            };
+            if v > (i64::MIN as u64) / unit {
+                // overflow
+                return Err(DurationParseError::Overflow { offset: start });
+            }
+
+            v *= unit;
+            if f > 0 {
+                v += scale_fraction(f, digits, unit);
+                if v > (i64::MIN as u64) {
+                    return Err(DurationParseError::Overflow { offset: start });
+                }
+            }
+            d = match d.checked_add(v) {
+                Some(d) if d <= (i64::MIN as u64) => d,
+                _ => return Err(DurationParseError::Overflow { offset: start }),
+            };
+        }
+
+        if neg {
+            let mut d = d as i64;
+            if d != i64::MIN {
+                d = -d;
+            }
+
+            return Ok(Duration(d));
+        }
+
+        if d > (i64::MAX as u64) {
+            return Err(DurationParseError::Overflow { offset: 0 });
+        }
+
+        Ok(Duration(d as i64))
+    }
+
+    /// Returns the registered suffix closest to the unknown `unit`, if any is
+    /// close enough to be what was meant: one differing only by case, else the
+    /// longest one `unit` starts with, as "s" for "sec", else the nearest one
+    /// by edit distance, as "ns" for "nx", within 2 edits and without
+    /// replacing every character.
+    fn suggest(&self, unit: &str) -> Option<&str> {
+        let suffixes = || self.units.iter().map(|(k, _)| k);
+
+        if let Some(v) = suffixes().filter(|v| v.eq_ignore_ascii_case(unit)).min() {
+            return Some(v);
+        }
+
+        let prefix = suffixes()
+            .filter(|v| unit.starts_with(v))
+            .max_by(|a, b| a.len().cmp(&b.len()).then(b.cmp(a)));
+        if let Some(v) = prefix {
+            return Some(v);
+        }
+
+        suffixes()
+            .filter_map(|v| {
+                let d = edit_distance(unit, v)?;
+                let n = unit.chars().count().max(v.chars().count());
+                (d <= 2 && d < n).then_some((d, v))
+            })
+            .min()
+            .map(|(_, v)| v)
+    }
+
+    /// Registers `suffix` as a unit worth `unit`, replacing any previous unit
+    /// of the same suffix.
+    ///
+    /// # Panics
+    /// This function panics if `suffix` is empty or contains a digit or '.',
+    /// or if `unit` isn't positive.
+    pub fn with_unit<S>(mut self, suffix: S, unit: Duration) -> Self
+    where
+        S: Into<String>,
+    {
+        let suffix = suffix.into();
+        assert!(
+            !suffix.is_empty() && !suffix.contains(|c: char| c == '.' || c.is_ascii_digit()),
+            "invalid unit suffix {}",
+            crate::quote(&suffix)
+        );
+        assert!(unit.0 > 0, "non-positive unit {unit}");
+
+        self.units.to_mut().insert(suffix, unit.0 as u64);
+        self
+    }
+
+    /// Removes the unit of `suffix` if registered.
+    pub fn without_unit<S>(mut self, suffix: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.units.to_mut().remove(suffix.as_ref());
+        self
+    }
+
+    /// Returns a parser of the built-in units `table` making up `set`, looked
+    /// up by `lookup`.
+    pub(super) const fn builtin(
+        table: &'static [(&'static str, u64)],
+        lookup: fn(&[u8]) -> Option<u64>,
+        set: UnitSet,
+        whitespace: bool,
+    ) -> Self {
+        Self {
+            units: Units::Builtin(table, lookup, set),
+            whitespace,
+        }
+    }
+}
+
+impl Units {
+    /// Returns the nanoseconds of the unit `suffix`, if registered.
+    fn get(&self, suffix: &[u8]) -> Option<u64> {
+        match self {
+            Self::Builtin(_, lookup, _) => lookup(suffix),
+            Self::Custom(units) => str::from_utf8(suffix)
+                .ok()
+                .and_then(|v| units.get(v))
+                .copied(),
+        }
+    }
+
+    /// Returns the registered suffixes along with their nanoseconds.
+    fn iter(&self) -> impl Iterator<Item = (&str, u64)> + '_ {
+        let (table, units) = match self {
+            Self::Builtin(table, ..) => (*table, None),
+            Self::Custom(units) => (&[][..], Some(units)),
+        };
+
+        table.iter().copied().chain(
+            units
+                .into_iter()
+                .flat_map(|v| v.iter().map(|(k, v)| (k.as_str(), *v))),
+        )
+    }
+
+    /// Returns the set the units make up.
+    fn set(&self) -> UnitSet {
+        match self {
+            Self::Builtin(.., set) => *set,
+            Self::Custom(_) => UnitSet::Custom,
+        }
+    }
+
+    /// Returns the registered units for modification, copying built-in ones
+    /// first.
+    fn to_mut(&mut self) -> &mut BTreeMap<String, u64> {
+        if let Self::Builtin(table, ..) = self {
+            *self = Self::Custom(table.iter().map(|(k, v)| (String::from(*k), *v)).collect());
+        }
+
+        match self {
+            Self::Custom(units) => units,
+            Self::Builtin(..) => unreachable!(),
+        }
+    }
+}
+
+impl Default for DurationParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the nanoseconds of the unit `suffix` of [DEFAULT_UNITS].
+fn default_unit(suffix: &[u8]) -> Option<u64> {
+    let v = match suffix {
+        b"ns" => NANOSECOND,
+        // "µs" is U+00B5 and "μs" is U+03BC in UTF-8.
+        b"us" | b"\xc2\xb5s" | b"\xce\xbcs" => MICROSECOND,
+        b"ms" => MILLISECOND,
+        b"s" => SECOND,
+        b"m" => MINUTE,
+        b"h" => HOUR,
+        _ => return None,
+    };
+
+    Some(v.0 as u64)
+}
+
+/// Returns the nanoseconds of the unit `suffix` of [EXTENDED_UNITS].
+#[cfg(feature = "days")]
+fn extended_unit(suffix: &[u8]) -> Option<u64> {
+    match suffix {
+        b"d" => Some(super::DAY.0 as u64),
+        b"w" => Some(super::WEEK.0 as u64),
+        _ => default_unit(suffix),
+    }
+}
+
+/// Returns the Levenshtein distance between `a` and `b` in characters, or
+/// `None` if `a` is longer than [UnitSuffix::CAPACITY] characters. It works
+/// on the stack, so that reporting an unknown unit doesn't allocate.
+fn edit_distance(a: &str, b: &str) -> Option<usize> {
+    const N: usize = UnitSuffix::CAPACITY;
+
+    let mut chars = ['\0'; N];
+    let mut n = 0;
+    for c in a.chars() {
+        *chars.get_mut(n)? = c;
+        n += 1;
+    }
+    let a = &chars[..n];
+
+    // The distances between the prefixes of `a` and the current one of `b`.
+    let mut row = [0; N + 1];
+    for (i, v) in row.iter_mut().enumerate() {
+        *v = i;
+    }
+
+    for (j, cb) in b.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = j + 1;
+        for (i, ca) in a.iter().enumerate() {
+            let v = (diag + (*ca != cb) as usize)
+                .min(row[i] + 1)
+                .min(row[i + 1] + 1);
+            diag = row[i + 1];
+            row[i + 1] = v;
+        }
+    }
+
+    Some(row[n])
+}
+
+/// Returns `s` without leading and trailing ASCII whitespace.
+fn trim_whitespace(s: &[u8]) -> &[u8] {
+    let i = s.iter().take_while(|c| c.is_ascii_whitespace()).count();
+    let j = s
+        .iter()
+        .rev()
+        .take_while(|c| c.is_ascii_whitespace())
+        .count();
+
+    &s[i..(s.len() - j).max(i)]
+}
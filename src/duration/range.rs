@@ -0,0 +1,82 @@
+use core::iter::FusedIterator;
+
+use super::Duration;
+
+/// A DurationRange iterates over the durations from `start` up to, but
+/// excluding, `end`, stepped by `step`, such as when generating backoff
+/// schedules or histogram edges.
+///
+/// A negative step iterates downward from `start` to `end`. The iteration
+/// stops instead of overflowing when the next duration would exceed the range
+/// of a [Duration].
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/duration_range.rs")]
+/// ```
+#[derive(Clone, Debug)]
+pub struct DurationRange {
+    next: Option<Duration>,
+    end: Duration,
+    step: Duration,
+}
+
+impl DurationRange {
+    /// Returns a DurationRange from `start` to `end` stepped by `step`.
+    ///
+    /// # Panics
+    /// This function panics if `step` is zero.
+    pub fn new(start: Duration, end: Duration, step: Duration) -> Self {
+        assert!(step.0 != 0, "zero step in DurationRange");
+
+        Self {
+            next: Some(start),
+            end,
+            step,
+        }
+    }
+
+    fn remaining(&self) -> u128 {
+        let (next, end, step) = match self.next {
+            Some(next) => (next.0 as i128, self.end.0 as i128, self.step.0 as i128),
+            None => return 0,
+        };
+
+        let span = if step > 0 { end - next } else { next - end };
+        if span <= 0 {
+            return 0;
+        }
+
+        let step = step.unsigned_abs();
+        (span as u128 + step - 1) / step
+    }
+}
+
+impl Iterator for DurationRange {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let d = self.next?;
+        let within = if self.step.0 > 0 {
+            d < self.end
+        } else {
+            d > self.end
+        };
+        if !within {
+            self.next = None;
+            return None;
+        }
+
+        self.next = d.0.checked_add(self.step.0).map(Duration);
+        Some(d)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for DurationRange {}
@@ -0,0 +1,43 @@
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation};
+use schemars::JsonSchema;
+
+use super::Duration;
+
+/// Pattern of the strings accepted by [parse_duration](crate::parse_duration).
+const PATTERN: &str = r"^[-+]?(0|(([0-9]+(\.[0-9]*)?|\.[0-9]+)(ns|us|µs|μs|ms|s|m|h))+)$";
+
+/// Describes the duration as its string form, such as "1h30m0s", as
+/// serialized when the `serde` feature is enabled.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/duration_json_schema.rs")]
+/// ```
+impl JsonSchema for Duration {
+    fn schema_name() -> String {
+        "Duration".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some(
+                    "A possibly signed sequence of decimal numbers, each with optional \
+                     fraction and a unit suffix among \"ns\", \"us\" (or \"µs\"), \"ms\", \
+                     \"s\", \"m\" and \"h\"."
+                        .to_string(),
+                ),
+                examples: vec!["1h30m0s".into(), "300ms".into(), "-1.5h".into()],
+                ..Default::default()
+            })),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(PATTERN.to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
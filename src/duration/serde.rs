@@ -0,0 +1,156 @@
+use core::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use super::{Duration, SECOND};
+use crate::DurationOutOfRangeError;
+
+/// Serializes the duration in its string form, such as "1h30m0s", for
+/// human-readable formats, and as an integer nanosecond count otherwise.
+impl Serialize for Duration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_i64(self.0)
+        }
+    }
+}
+
+/// Deserializes the duration from either a string accepted by
+/// [parse_duration](crate::parse_duration), such as "1h30m", an integer
+/// nanosecond count, or an object of seconds and nanoseconds, such as
+/// `{"secs": 5, "nanos": 500000000}`, as other serializers of durations emit.
+/// The object may name its seconds "seconds" instead and omit its nanoseconds
+/// for zero, but the seconds are required, other fields are rejected, and
+/// non-zero seconds and nanoseconds must have the same sign. The nanoseconds
+/// must be less than a second in magnitude.
+///
+/// Formats that aren't human-readable, which may not be self-describing, are
+/// only read an integer nanosecond count from, as serialized.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/duration_serde.rs")]
+/// ```
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(DurationVisitor)
+        } else {
+            deserializer.deserialize_i64(DurationVisitor)
+        }
+    }
+}
+
+struct DurationVisitor;
+
+impl<'de> Visitor<'de> for DurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a duration string, an integer nanosecond count or seconds and nanoseconds")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let (mut secs, mut nanos) = (None, None);
+        while let Some(key) = map.next_key()? {
+            let (field, name) = match key {
+                Field::Secs => (&mut secs, "secs"),
+                Field::Nanos => (&mut nanos, "nanos"),
+            };
+            if field.is_some() {
+                return Err(de::Error::duplicate_field(name));
+            }
+            *field = Some(map.next_value::<i64>()?);
+        }
+
+        let secs = secs.ok_or_else(|| de::Error::missing_field("secs"))?;
+        let nanos = nanos.unwrap_or(0);
+        if nanos.unsigned_abs() >= SECOND.0 as u64 {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Signed(nanos),
+                &"nanoseconds from -999999999 to 999999999",
+            ));
+        }
+        if secs.signum() * nanos.signum() < 0 {
+            return Err(de::Error::custom(
+                "seconds and nanoseconds of opposite signs",
+            ));
+        }
+
+        secs.checked_mul(SECOND.0)
+            .and_then(|v| v.checked_add(nanos))
+            .map(Duration)
+            .ok_or_else(|| de::Error::custom(DurationOutOfRangeError))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Duration(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        i64::try_from(v)
+            .map(Duration)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// The fields of a duration given as seconds and nanoseconds.
+enum Field {
+    Secs,
+    Nanos,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl<'de> Visitor<'de> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("`secs` or `nanos`")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            "secs" | "seconds" => Ok(Field::Secs),
+            "nanos" => Ok(Field::Nanos),
+            _ => Err(E::unknown_field(v, &["secs", "seconds", "nanos"])),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{Duration, LongDuration};
+
+/// Number of bits of the magnitude kept by a quantile bucket beyond its
+/// leading one, bounding the relative error of quantiles by 2^-SUB_BITS.
+const SUB_BITS: u32 = 5;
+const SUB_BUCKETS: usize = 1 << SUB_BITS;
+/// Buckets needed to cover every u64 magnitude.
+const BUCKETS: usize = (64 - SUB_BITS as usize + 1) * SUB_BUCKETS;
+
+/// A DurationStats accumulates durations, such as request latencies, into
+/// their count, minimum, maximum and mean without keeping the samples.
+///
+/// Quantiles are only tracked by stats created with
+/// [DurationStats::with_quantiles], which records the samples into
+/// logarithmic buckets of about 3% relative width.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/duration_stats.rs")]
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DurationStats {
+    count: u64,
+    sum: i128,
+    min: Option<Duration>,
+    max: Option<Duration>,
+    buckets: Option<Box<Buckets>>,
+}
+
+#[derive(Clone, Debug)]
+struct Buckets {
+    negative: Vec<u64>,
+    positive: Vec<u64>,
+}
+
+impl DurationStats {
+    /// Returns empty stats which don't track quantiles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns empty stats which also track quantiles.
+    pub fn with_quantiles() -> Self {
+        Self {
+            buckets: Some(Box::new(Buckets {
+                negative: vec![0; BUCKETS],
+                positive: vec![0; BUCKETS],
+            })),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the number of durations accumulated.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the largest duration accumulated, or `None` if there is none.
+    pub fn max(&self) -> Option<Duration> {
+        self.max
+    }
+
+    /// Returns the arithmetic mean of the durations accumulated, rounded
+    /// toward zero to the nearest nanosecond, or `None` if there is none.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+
+        // The mean lies between min and max, so it fits in a Duration.
+        Some(Duration((self.sum / self.count as i128) as i64))
+    }
+
+    /// Merges the durations accumulated by `other` into `self`. Quantiles are
+    /// kept only when both track them.
+    pub fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = match (self.min, other.min) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.max = match (self.max, other.max) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+
+        match (&mut self.buckets, &other.buckets) {
+            (Some(a), Some(b)) => {
+                for (x, y) in a.negative.iter_mut().zip(&b.negative) {
+                    *x += y;
+                }
+                for (x, y) in a.positive.iter_mut().zip(&b.positive) {
+                    *x += y;
+                }
+            }
+            (a, None) => *a = None,
+            (None, Some(_)) => {}
+        }
+    }
+
+    /// Returns the smallest duration accumulated, or `None` if there is none.
+    pub fn min(&self) -> Option<Duration> {
+        self.min
+    }
+
+    /// Accumulates `d`.
+    pub fn push(&mut self, d: Duration) {
+        self.count += 1;
+        self.sum += d.0 as i128;
+        self.min = Some(self.min.map_or(d, |v| v.min(d)));
+        self.max = Some(self.max.map_or(d, |v| v.max(d)));
+
+        if let Some(buckets) = &mut self.buckets {
+            let i = bucket_index(d.0.unsigned_abs());
+            if d.0 < 0 {
+                buckets.negative[i] += 1;
+            } else {
+                buckets.positive[i] += 1;
+            }
+        }
+    }
+
+    /// Returns an estimate of the `q`-quantile of the durations accumulated,
+    /// such as the median for 0.5 or the 99th percentile for 0.99, within
+    /// about 3% of the exact value. It returns `None` if there is no duration
+    /// or quantiles aren't tracked.
+    ///
+    /// # Panics
+    /// This function panics if `q` isn't within [0, 1].
+    pub fn quantile(&self, q: f64) -> Option<Duration> {
+        assert!((0.0..=1.0).contains(&q), "quantile out of [0, 1]");
+
+        let buckets = self.buckets.as_ref()?;
+        let (min, max) = (self.min?, self.max?);
+
+        // The rank is ceil(q * count), computed without std.
+        let x = q * self.count as f64;
+        let rank = (x as u64 + ((x as u64 as f64) < x) as u64).clamp(1, self.count);
+        // The extremes are known exactly.
+        if rank == 1 {
+            return Some(min);
+        } else if rank == self.count {
+            return Some(max);
+        }
+
+        let negative = buckets.negative.iter().enumerate().rev();
+        let negative = negative.map(|(i, n)| (-(bucket_mid(i) as i128), n));
+        let positive = buckets.positive.iter().enumerate();
+        let positive = positive.map(|(i, n)| (bucket_mid(i) as i128, n));
+
+        let mut seen = 0;
+        for (v, n) in negative.chain(positive) {
+            seen += n;
+            if seen >= rank {
+                let v = v.clamp(min.0 as i128, max.0 as i128);
+                return Some(Duration(v as i64));
+            }
+        }
+
+        unreachable!("rank within count")
+    }
+
+    /// Returns the sum of the durations accumulated, which may exceed the
+    /// range of a [Duration].
+    pub fn sum(&self) -> LongDuration {
+        LongDuration(self.sum)
+    }
+}
+
+impl Extend<Duration> for DurationStats {
+    fn extend<T: IntoIterator<Item = Duration>>(&mut self, iter: T) {
+        for d in iter {
+            self.push(d);
+        }
+    }
+}
+
+/// Collects durations into stats which don't track quantiles.
+impl FromIterator<Duration> for DurationStats {
+    fn from_iter<T: IntoIterator<Item = Duration>>(iter: T) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+/// Returns the index of the bucket holding the magnitude `v`. Magnitudes below
+/// SUB_BUCKETS have buckets of their own, while larger ones share buckets by
+/// their leading SUB_BITS + 1 bits.
+fn bucket_index(v: u64) -> usize {
+    if v < SUB_BUCKETS as u64 {
+        return v as usize;
+    }
+
+    let e = 63 - v.leading_zeros();
+    let m = (v >> (e - SUB_BITS)) as usize & (SUB_BUCKETS - 1);
+    (e - SUB_BITS + 1) as usize * SUB_BUCKETS + m
+}
+
+/// Returns the magnitude in the middle of the bucket `i`.
+fn bucket_mid(i: usize) -> u64 {
+    if i < SUB_BUCKETS {
+        return i as u64;
+    }
+
+    let (g, m) = (i / SUB_BUCKETS, i % SUB_BUCKETS);
+    let low = ((SUB_BUCKETS + m) as u64) << (g - 1);
+    let width = 1u64 << (g - 1);
+    low + (width - 1) / 2
+}
@@ -0,0 +1,48 @@
+use core::ops::{Bound, RangeBounds, RangeInclusive};
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{Map, Strategy};
+
+use super::Duration;
+
+/// Generates any duration, from i64::MIN to i64::MAX nanoseconds, so that
+/// `any::<Duration>()` works.
+impl Arbitrary for Duration {
+    type Parameters = ();
+    type Strategy = Map<<i64 as Arbitrary>::Strategy, fn(i64) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<i64>().prop_map(Duration)
+    }
+}
+
+/// Returns a proptest strategy generating durations within `range`, such as
+/// `SECOND..MINUTE`.
+///
+/// # Panics
+/// It panics if `range` is empty.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/duration_strategy.rs")]
+/// ```
+pub fn duration_strategy<R>(range: R) -> Map<RangeInclusive<i64>, fn(i64) -> Duration>
+where
+    R: RangeBounds<Duration>,
+{
+    let low = match range.start_bound() {
+        Bound::Included(d) => Some(d.0),
+        Bound::Excluded(d) => d.0.checked_add(1),
+        Bound::Unbounded => Some(i64::MIN),
+    };
+    let high = match range.end_bound() {
+        Bound::Included(d) => Some(d.0),
+        Bound::Excluded(d) => d.0.checked_sub(1),
+        Bound::Unbounded => Some(i64::MAX),
+    };
+
+    match (low, high) {
+        (Some(low), Some(high)) if low <= high => (low..=high).prop_map(Duration),
+        _ => panic!("empty duration range"),
+    }
+}
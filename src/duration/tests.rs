@@ -1,4 +1,10 @@
-use super::{Duration, MAX_DURATION, MINUTE, MIN_DURATION};
+// Lints tripped by tests written before clippy was enforced, kept as written.
+#![allow(clippy::identity_op, clippy::neg_multiply)]
+
+use super::macros::parse_duration_const;
+use super::{
+    leading_8_digits, leading_fraction, leading_int, Duration, MAX_DURATION, MINUTE, MIN_DURATION,
+};
 
 #[test]
 fn abs() {
@@ -27,3 +33,87 @@ fn abs() {
         assert_eq!(c.d.abs(), c.want, "#{i}");
     }
 }
+
+#[test]
+fn swar_leading_8_digits() {
+    let test_vector: Vec<(&[u8], Option<u64>)> = vec![
+        (b"00000000", Some(0)),
+        (b"12345678", Some(12345678)),
+        (b"99999999", Some(99999999)),
+        (b"01234567s", Some(1234567)),
+        (b"1234567", None),
+        (b"", None),
+    ];
+
+    for (i, (s, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, leading_8_digits(s), "#{i}");
+    }
+
+    // Any non-digit byte at any position is caught.
+    for pos in 0..8 {
+        for c in (0..=255u8).filter(|c| !c.is_ascii_digit()) {
+            let mut s = *b"99999999";
+            s[pos] = c;
+            assert_eq!(None, leading_8_digits(&s), "byte {c:#x} at {pos}");
+        }
+    }
+}
+
+#[test]
+fn leading_digits_match_bytewise() {
+    let test_vector: Vec<&[u8]> = vec![
+        b"0",
+        b"1234567890123456789s",
+        b"9223372036854775808",
+        b"9223372036854775809",
+        b"00000000000000000000000000001h",
+        b"12345678.5",
+        b"999999999999999999999999999999",
+    ];
+
+    for (i, s) in test_vector.into_iter().enumerate() {
+        let n = s.iter().take_while(|c| c.is_ascii_digit()).count();
+        let digits = s[..n].iter().map(|c| (c - b'0') as u128);
+
+        let v = digits
+            .clone()
+            .try_fold(0u128, |x, d| Some(x * 10 + d).filter(|x| *x <= 1 << 63));
+        let want = v.map(|v| (v as u64, &s[n..]));
+        assert_eq!(want, leading_int(s), "#{i}");
+
+        // The fraction keeps the digits fitting into i64, counting them.
+        let (mut x, mut count) = (0i64, 0);
+        for d in digits {
+            match x.checked_mul(10).and_then(|x| x.checked_add(d as i64)) {
+                Some(y) => (x, count) = (y, count + 1),
+                None => break,
+            }
+        }
+        assert_eq!((x as u64, count, &s[n..]), leading_fraction(s), "#{i}");
+    }
+}
+
+#[test]
+fn parse_duration_const_errors() {
+    let test_vector = vec![
+        ("", "invalid duration"),
+        ("3", "missing unit in duration"),
+        ("1x", "unknown unit in duration"),
+        ("1sec", "unknown unit in duration"),
+        ("-.s", "invalid duration"),
+        ("9223372036854775808ns", "duration out of range"),
+        ("2562048h", "duration out of range"),
+        (
+            "9223372036854775808ns9223372036854775808ns",
+            "duration out of range",
+        ),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(
+            Err(want),
+            parse_duration_const(input),
+            "#{i} parse '{input}'"
+        );
+    }
+}
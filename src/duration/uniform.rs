@@ -0,0 +1,42 @@
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler};
+use rand::Rng;
+
+use super::Duration;
+
+/// UniformDuration samples durations uniformly from a range, which makes
+/// `rng.gen_range(SECOND..5 * SECOND)` work for [Duration].
+///
+/// # Example
+/// ```
+#[doc = include_str!("../../examples/duration_gen_range.rs")]
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniformDuration(UniformInt<i64>);
+
+impl SampleUniform for Duration {
+    type Sampler = UniformDuration;
+}
+
+impl UniformSampler for UniformDuration {
+    type X = Duration;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new(low.borrow().0, high.borrow().0))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new_inclusive(low.borrow().0, high.borrow().0))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Duration(self.0.sample(rng))
+    }
+}
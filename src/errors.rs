@@ -1,10 +1,415 @@
-/// Errors for parsing durations.
-#[derive(thiserror::Error, Debug)]
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{self, Debug, Display};
+use core::str;
+
+/// Errors for parsing durations. Each error carries the byte offset of the
+/// input where parsing failed, which [DurationParseError::diagnostic] points
+/// at.
+///
+/// No variant owns heap memory: unknown units are kept in an inline
+/// [UnitSuffix], and the input itself isn't kept but passed back by callers
+/// wanting to quote it. Creating an error thus never allocates, which keeps
+/// rejecting many bad inputs cheap and doesn't tie the error API to an
+/// allocator.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum DurationParseError {
-    #[error("invalid duration")]
-    Invalid,
-    #[error("missing unit in duration")]
-    MissUnit,
-    #[error("unknown unit {}", crate::quote(.unit))]
-    UnknownUnit { unit: String },
+    /// The input isn't a valid duration from byte `offset` on.
+    Invalid { offset: usize },
+    /// The number ending at byte `offset` of the input lacks a unit.
+    MissUnit { offset: usize },
+    /// The duration is well-formed but out of the range of
+    /// [Duration](crate::Duration). `offset` is where the component making it
+    /// overflow starts, or 0 if the total does.
+    Overflow { offset: usize },
+    /// The unit `unit` spanning `len` bytes from byte `offset` of the input
+    /// isn't known. `len` is the length of the unit in the input, which
+    /// `unit` may differ from once truncated or with invalid UTF-8 replaced.
+    /// `suggestion` is the closest known unit, if any is close enough, and
+    /// `accepted` tells the units accepted instead.
+    UnknownUnit {
+        unit: UnitSuffix,
+        offset: usize,
+        len: usize,
+        suggestion: Option<UnitSuffix>,
+        accepted: UnitSet,
+    },
 }
+
+// Results of parsing carry the error, so keep it small for validation loops:
+// two offsets, two inline suffixes and a tag.
+const _: () = assert!(core::mem::size_of::<DurationParseError>() <= 56);
+
+/// The set of units of the parser reporting [DurationParseError::UnknownUnit],
+/// which tells the units it accepts without keeping them in the error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UnitSet {
+    /// The units of [parse_duration](crate::parse_duration).
+    Default,
+    /// The units of `parse_duration_extended`, adding days and weeks.
+    Extended,
+    /// The units of [DurationParser::humantime](crate::DurationParser::humantime).
+    Humantime,
+    /// Units customized by a [DurationParser](crate::DurationParser), which
+    /// [DurationParser::accepted_units](crate::DurationParser::accepted_units)
+    /// lists.
+    Custom,
+}
+
+impl UnitSet {
+    /// Returns the units of a built-in set as
+    /// [DurationParser::accepted_units](crate::DurationParser::accepted_units)
+    /// lists them, such as "ns, us/µs/μs, ms, s, m, h", or `None` for custom
+    /// units.
+    pub fn units(&self) -> Option<&'static str> {
+        match self {
+            Self::Default => Some("ns, us/µs/μs, ms, s, m, h"),
+            Self::Extended => Some("ns, us/µs/μs, ms, s, m, h, d, w"),
+            Self::Humantime => Some(
+                "nanos/ns/nsec, us/usec, millis/ms/msec, s/sec/second/seconds/secs, \
+                 m/min/mins/minute/minutes, h/hour/hours/hr/hrs, d/day/days, w/week/weeks, \
+                 M/month/months, y/year/years",
+            ),
+            Self::Custom => None,
+        }
+    }
+}
+
+/// The suffix of an unknown unit reported by [DurationParseError], stored
+/// inline. Suffixes longer than [UnitSuffix::CAPACITY] bytes are truncated,
+/// and invalid UTF-8 is replaced by U+FFFD.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitSuffix {
+    buf: [u8; UnitSuffix::CAPACITY],
+    len: u8,
+    truncated: bool,
+}
+
+impl UnitSuffix {
+    /// Maximum number of bytes stored.
+    pub const CAPACITY: usize = 16;
+
+    pub(crate) fn new(mut s: &[u8]) -> Self {
+        let mut v = Self {
+            buf: [0; Self::CAPACITY],
+            len: 0,
+            truncated: false,
+        };
+
+        while !s.is_empty() {
+            let (valid, skip) = match str::from_utf8(s) {
+                Ok(valid) => (valid, s.len()),
+                Err(err) => {
+                    let n = err.valid_up_to();
+                    let skip = n + err.error_len().unwrap_or(s.len() - n);
+                    (unsafe { str::from_utf8_unchecked(&s[..n]) }, skip)
+                }
+            };
+
+            for c in valid
+                .chars()
+                .chain((skip > valid.len()).then_some('\u{fffd}'))
+            {
+                if !v.push(c) {
+                    v.truncated = true;
+                    return v;
+                }
+            }
+            s = &s[skip..];
+        }
+
+        v
+    }
+
+    /// Returns the suffix as a string slice.
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+    }
+
+    /// Returns true if the suffix was longer than [UnitSuffix::CAPACITY] bytes
+    /// and got truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    fn push(&mut self, c: char) -> bool {
+        let (len, n) = (self.len as usize, c.len_utf8());
+        if len + n > Self::CAPACITY {
+            return false;
+        }
+
+        c.encode_utf8(&mut self.buf[len..]);
+        self.len += n as u8;
+        true
+    }
+}
+
+impl AsRef<str> for UnitSuffix {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Debug for UnitSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for UnitSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl DurationParseError {
+    /// Returns a two-line report of the error, showing the original `input`
+    /// with a caret under the byte where parsing failed, such as
+    ///
+    /// ```text
+    /// 1h30x
+    ///     ^ unknown unit "x" (valid units: ns, us/µs/μs, ms, s, m, h)
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../examples/duration_parse_error_diagnostic.rs")]
+    /// ```
+    pub fn diagnostic<S>(&self, input: S) -> String
+    where
+        S: AsRef<str>,
+    {
+        let input = input.as_ref();
+        let offset = self.offset();
+        let column = input
+            .get(..offset)
+            .map_or(offset, |prefix| prefix.chars().count());
+
+        format!("{input}\n{:column$}^ {self}", "")
+    }
+
+    /// Returns the message Go's `time.ParseDuration` reports for the same
+    /// error, quoting the original `input`, such as
+    /// `time: unknown unit "x" in duration "1x"`.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../examples/duration_parse_error_go_message.rs")]
+    /// ```
+    pub fn go_message<S>(&self, input: S) -> String
+    where
+        S: AsRef<str>,
+    {
+        let input = crate::go_quote(input);
+        match self {
+            // Go doesn't tell overflows apart.
+            Self::Invalid { .. } | Self::Overflow { .. } => {
+                format!("time: invalid duration {input}")
+            }
+            Self::MissUnit { .. } => format!("time: missing unit in duration {input}"),
+            Self::UnknownUnit { unit, .. } => {
+                format!(
+                    "time: unknown unit {} in duration {input}",
+                    crate::go_quote(unit)
+                )
+            }
+        }
+    }
+
+    /// Returns the byte offset of the input where parsing failed.
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::Invalid { offset }
+            | Self::MissUnit { offset }
+            | Self::Overflow { offset }
+            | Self::UnknownUnit { offset, .. } => *offset,
+        }
+    }
+}
+
+impl Display for DurationParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid { .. } => f.write_str("invalid duration"),
+            Self::MissUnit { .. } => f.write_str("missing unit in duration"),
+            Self::Overflow { .. } => f.write_str("duration out of range"),
+            Self::UnknownUnit {
+                unit,
+                suggestion,
+                accepted,
+                ..
+            } => {
+                write!(f, "unknown unit {}", crate::quote(unit))?;
+                if let Some(v) = suggestion {
+                    write!(f, "; did you mean \"{v}\"?")?;
+                }
+                match accepted.units() {
+                    Some(v) => write!(f, " (valid units: {v})"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationParseError {}
+
+/// Errors for decoding the binary encoding of [Duration::to_bytes](crate::Duration::to_bytes).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryDecodeError {
+    /// The input has `len` bytes instead of the length of the encoding.
+    InvalidLength { len: usize },
+    /// The input starts with an unknown version byte.
+    UnknownVersion(u8),
+}
+
+impl Display for BinaryDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { len } => write!(f, "invalid length {len} of encoded duration"),
+            Self::UnknownVersion(v) => write!(f, "unknown version {v} of encoded duration"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BinaryDecodeError {}
+
+/// Errors for parsing naive dates and times, carrying the byte offset of the
+/// input where parsing failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateTimeParseError {
+    /// The input isn't a valid date and time from byte `offset` on.
+    Invalid { offset: usize },
+    /// The field starting at byte `offset` is out of range, such as month 13,
+    /// February 30 or hour 24.
+    OutOfRange { offset: usize },
+}
+
+impl DateTimeParseError {
+    /// Returns the byte offset of the input where parsing failed.
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::Invalid { offset } | Self::OutOfRange { offset } => *offset,
+        }
+    }
+}
+
+impl Display for DateTimeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid { .. } => f.write_str("invalid date and time"),
+            Self::OutOfRange { .. } => f.write_str("date and time out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateTimeParseError {}
+
+/// Errors for converting floating point numbers into durations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DurationFromFloatError {
+    /// The number is NaN.
+    NaN,
+    /// The number is infinite or out of the range of a duration.
+    Overflow,
+}
+
+impl Display for DurationFromFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NaN => f.write_str("cannot convert NaN to duration"),
+            Self::Overflow => f.write_str("duration overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationFromFloatError {}
+
+/// Error for converting a value out of the range of [Duration](crate::Duration)
+/// into one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DurationOutOfRangeError;
+
+impl Display for DurationOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("duration out of range")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationOutOfRangeError {}
+
+/// Error for converting a number out of [1, 12] into a [Month](crate::Month).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonthOutOfRangeError;
+
+impl Display for MonthOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("month out of range")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MonthOutOfRangeError {}
+
+/// Error for converting a negative duration into [std::time::Duration].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NegativeDurationError;
+
+impl Display for NegativeDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("negative duration cannot be represented by std::time::Duration")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NegativeDurationError {}
+
+/// Errors for parsing periods, carrying the byte offset of the input where
+/// parsing failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PeriodParseError {
+    /// The input isn't a valid period from byte `offset` on.
+    Invalid { offset: usize },
+    /// A component of the period is out of the range of `i32`. `offset` is
+    /// where the number making it overflow starts.
+    Overflow { offset: usize },
+}
+
+impl PeriodParseError {
+    /// Returns the byte offset of the input where parsing failed.
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::Invalid { offset } | Self::Overflow { offset } => *offset,
+        }
+    }
+}
+
+impl Display for PeriodParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid { .. } => f.write_str("invalid period"),
+            Self::Overflow { .. } => f.write_str("period out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PeriodParseError {}
+
+/// Error for converting a number out of range into a [Weekday](crate::Weekday).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeekdayOutOfRangeError;
+
+impl Display for WeekdayOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("weekday out of range")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeekdayOutOfRangeError {}
@@ -0,0 +1,208 @@
+//! A C interface to durations, behind the `ffi` feature, so that C and C++
+//! callers, and other languages through their C FFI, get the same Go-compatible
+//! parsing, formatting and arithmetic.
+//!
+//! Durations cross the boundary as `int64_t` nanosecond counts. Fallible
+//! functions return a [TimeDurationResult] whose status tells what went
+//! wrong. Build the crate as a `cdylib` or `staticlib` to link it, e.g. with
+//! `cargo rustc --release --features ffi --crate-type staticlib`.
+
+use core::ffi::{c_char, CStr};
+use core::fmt::{self, Write};
+use core::{ptr, slice};
+
+use crate::{Duration, DurationParseError};
+
+/// The status of a [TimeDurationResult].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeStatus {
+    /// The operation succeeded.
+    Ok = 0,
+    /// The input isn't a valid duration.
+    InvalidDuration = 1,
+    /// A number of the input lacks a unit.
+    MissingUnit = 2,
+    /// The result is out of the range of a duration.
+    Overflow = 3,
+    /// A unit of the input isn't known.
+    UnknownUnit = 4,
+    /// A pointer argument is null.
+    NullPointer = 5,
+}
+
+/// The result of a fallible operation: a duration in nanoseconds if `status`
+/// is [TimeStatus::Ok]. For parse errors, `offset` is the byte offset of the
+/// input where parsing failed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimeDurationResult {
+    pub status: TimeStatus,
+    pub offset: usize,
+    pub nanoseconds: i64,
+}
+
+impl From<Result<Duration, DurationParseError>> for TimeDurationResult {
+    fn from(value: Result<Duration, DurationParseError>) -> Self {
+        let err = match value {
+            Ok(d) => return Self::ok(d),
+            Err(err) => err,
+        };
+
+        let status = match err {
+            DurationParseError::Invalid { .. } => TimeStatus::InvalidDuration,
+            DurationParseError::MissUnit { .. } => TimeStatus::MissingUnit,
+            DurationParseError::Overflow { .. } => TimeStatus::Overflow,
+            DurationParseError::UnknownUnit { .. } => TimeStatus::UnknownUnit,
+        };
+        Self {
+            status,
+            offset: err.offset(),
+            nanoseconds: 0,
+        }
+    }
+}
+
+impl TimeDurationResult {
+    fn error(status: TimeStatus) -> Self {
+        Self {
+            status,
+            offset: 0,
+            nanoseconds: 0,
+        }
+    }
+
+    fn ok(d: Duration) -> Self {
+        Self {
+            status: TimeStatus::Ok,
+            offset: 0,
+            nanoseconds: d.0,
+        }
+    }
+}
+
+/// Returns `a + b`, or [TimeStatus::Overflow] if it's out of range.
+#[no_mangle]
+pub extern "C" fn time_duration_add(a: i64, b: i64) -> TimeDurationResult {
+    checked(a.checked_add(b))
+}
+
+/// Writes `d` formatted like "1h30m0s" into `buf` of `len` bytes, truncating
+/// it to fit and terminating it with a NUL byte if `len` isn't 0. It returns
+/// the length of the whole string without the NUL byte, like `snprintf`, which
+/// is never more than 25.
+///
+/// # Safety
+/// `buf` must be valid for writes of `len` bytes, or `len` must be 0.
+#[no_mangle]
+pub unsafe extern "C" fn time_duration_format(d: i64, buf: *mut c_char, len: usize) -> usize {
+    let mut s = Buf([0; 32], 0);
+    // Writing never fails as the longest duration takes 25 bytes.
+    let _ = write!(s, "{}", Duration(d));
+    let s = &s.0[..s.1];
+
+    if len > 0 && !buf.is_null() {
+        let n = s.len().min(len - 1);
+        ptr::copy_nonoverlapping(s.as_ptr(), buf.cast(), n);
+        *buf.add(n) = 0;
+    }
+
+    s.len()
+}
+
+/// Returns `d` as a floating point number of hours.
+#[no_mangle]
+pub extern "C" fn time_duration_hours(d: i64) -> f64 {
+    Duration(d).hours()
+}
+
+/// Returns `d` as a floating point number of minutes.
+#[no_mangle]
+pub extern "C" fn time_duration_minutes(d: i64) -> f64 {
+    Duration(d).minutes()
+}
+
+/// Returns `d * n`, or [TimeStatus::Overflow] if it's out of range.
+#[no_mangle]
+pub extern "C" fn time_duration_mul(d: i64, n: i64) -> TimeDurationResult {
+    checked(d.checked_mul(n))
+}
+
+/// Parses the NUL-terminated duration string `s`, such as "1h30m", the way
+/// [parse_duration](crate::parse_duration) does.
+///
+/// # Safety
+/// `s` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn time_duration_parse(s: *const c_char) -> TimeDurationResult {
+    if s.is_null() {
+        return TimeDurationResult::error(TimeStatus::NullPointer);
+    }
+
+    Duration::parse_bytes(CStr::from_ptr(s).to_bytes()).into()
+}
+
+/// Same as [time_duration_parse], but parses the `len` bytes at `s`, which
+/// don't need to be NUL-terminated.
+///
+/// # Safety
+/// `s` must be valid for reads of `len` bytes, or `len` must be 0.
+#[no_mangle]
+pub unsafe extern "C" fn time_duration_parse_bytes(s: *const u8, len: usize) -> TimeDurationResult {
+    if len == 0 {
+        return Duration::parse_bytes(b"").into();
+    }
+    if s.is_null() {
+        return TimeDurationResult::error(TimeStatus::NullPointer);
+    }
+
+    Duration::parse_bytes(slice::from_raw_parts(s, len)).into()
+}
+
+/// Returns `d` rounded half away from zero to a multiple of `m`, like
+/// [Duration::round].
+#[no_mangle]
+pub extern "C" fn time_duration_round(d: i64, m: i64) -> i64 {
+    Duration(d).round(Duration(m)).0
+}
+
+/// Returns `d` as a floating point number of seconds.
+#[no_mangle]
+pub extern "C" fn time_duration_seconds(d: i64) -> f64 {
+    Duration(d).seconds()
+}
+
+/// Returns `a - b`, or [TimeStatus::Overflow] if it's out of range.
+#[no_mangle]
+pub extern "C" fn time_duration_sub(a: i64, b: i64) -> TimeDurationResult {
+    checked(a.checked_sub(b))
+}
+
+/// Returns `d` rounded toward zero to a multiple of `m`, like
+/// [Duration::truncate].
+#[no_mangle]
+pub extern "C" fn time_duration_truncate(d: i64, m: i64) -> i64 {
+    Duration(d).truncate(Duration(m)).0
+}
+
+/// A fixed buffer of bytes and the number of them written.
+struct Buf([u8; 32], usize);
+
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let buf = self
+            .0
+            .get_mut(self.1..(self.1 + s.len()))
+            .ok_or(fmt::Error)?;
+        buf.copy_from_slice(s.as_bytes());
+        self.1 += s.len();
+        Ok(())
+    }
+}
+
+fn checked(v: Option<i64>) -> TimeDurationResult {
+    match v {
+        Some(v) => TimeDurationResult::ok(Duration(v)),
+        None => TimeDurationResult::error(TimeStatus::Overflow),
+    }
+}
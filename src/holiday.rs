@@ -0,0 +1,223 @@
+use alloc::borrow::Cow;
+
+use crate::{calendar, Month, Weekday, WeekendPolicy};
+
+/// A Holiday is a rule giving a day off once a year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Holiday {
+    /// The fixed `day` of `month`, such as December 25. If `observed` is set,
+    /// a holiday falling on a Saturday is also observed on the Friday before,
+    /// and one falling on a Sunday on the Monday after, as US federal
+    /// holidays are.
+    Fixed {
+        month: Month,
+        day: u8,
+        observed: bool,
+    },
+    /// The `n`-th `weekday` of `month`, counting from the end of the month for
+    /// negative `n`, as [Weekday::nth_of_month] does, such as the last Monday
+    /// of May for -1.
+    NthWeekday {
+        month: Month,
+        weekday: Weekday,
+        n: i8,
+    },
+    /// The day `offset` days from Western Easter Sunday, such as -2 for Good
+    /// Friday or 1 for Easter Monday. The day must fall in the same year.
+    Easter { offset: i16 },
+}
+
+/// A HolidayCalendar is a set of [Holiday] rules along with a
+/// [WeekendPolicy], telling business days apart from holidays and weekends.
+///
+/// Dates are given as a year, month and day. Presets are provided for common
+/// calendars, and custom ones are built from [HolidayCalendar::new] by adding
+/// holidays with [HolidayCalendar::with_holiday].
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/holiday_calendar.rs")]
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    holidays: Cow<'static, [Holiday]>,
+    weekend: WeekendPolicy,
+}
+
+impl HolidayCalendar {
+    /// The business calendar of the TARGET2 payment system of the euro area:
+    /// New Year's Day, Good Friday, Easter Monday, Labour Day on May 1,
+    /// Christmas Day and December 26, with Saturday and Sunday weekends.
+    pub const TARGET2: Self = Self::builtin(&[
+        fixed(Month::January, 1, false),
+        Holiday::Easter { offset: -2 },
+        Holiday::Easter { offset: 1 },
+        fixed(Month::May, 1, false),
+        fixed(Month::December, 25, false),
+        fixed(Month::December, 26, false),
+    ]);
+
+    /// The US federal holidays as set by 5 U.S.C. 6103, with holidays falling
+    /// on a weekend observed on the nearest weekday. The current rules are
+    /// applied to every year, including Juneteenth, first observed in 2021.
+    pub const US_FEDERAL: Self = Self::builtin(&[
+        fixed(Month::January, 1, true),
+        nth_weekday(Month::January, Weekday::Monday, 3),
+        nth_weekday(Month::February, Weekday::Monday, 3),
+        nth_weekday(Month::May, Weekday::Monday, -1),
+        fixed(Month::June, 19, true),
+        fixed(Month::July, 4, true),
+        nth_weekday(Month::September, Weekday::Monday, 1),
+        nth_weekday(Month::October, Weekday::Monday, 2),
+        fixed(Month::November, 11, true),
+        nth_weekday(Month::November, Weekday::Thursday, 4),
+        fixed(Month::December, 25, true),
+    ]);
+
+    /// Returns a calendar without holidays, with Saturday and Sunday
+    /// weekends.
+    pub const fn new() -> Self {
+        Self::builtin(&[])
+    }
+
+    /// Adds the holiday `holiday` to the calendar.
+    pub fn with_holiday(mut self, holiday: Holiday) -> Self {
+        self.holidays.to_mut().push(holiday);
+        self
+    }
+
+    /// Replaces the weekend of the calendar with `weekend`.
+    pub fn with_weekend(mut self, weekend: WeekendPolicy) -> Self {
+        self.weekend = weekend;
+        self
+    }
+
+    /// Returns the date `n` business days after the given one, or before it
+    /// for negative `n`. The date itself is returned for zero `n`, even if
+    /// it isn't a business day.
+    ///
+    /// # Panics
+    /// This function panics if the weekend spans the whole week.
+    pub fn add_business_days(&self, year: i32, month: Month, day: u8, n: i32) -> (i32, Month, u8) {
+        assert!(
+            Weekday::iter().any(|d| !self.weekend.contains(d)),
+            "no business days in the week"
+        );
+
+        let mut days = calendar::days_from_civil(year, month, day);
+        let step = n.signum() as i64;
+        let mut n = n.unsigned_abs();
+        while n > 0 {
+            days += step;
+            if self.is_business_day_at(days) {
+                n -= 1;
+            }
+        }
+
+        let (year, month, day) = calendar::civil_from_days(days);
+        (year as i32, month, day)
+    }
+
+    /// Returns the holidays of the calendar.
+    pub fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+
+    /// Reports whether `day` of `month` of `year` is neither a holiday nor on
+    /// the weekend.
+    pub fn is_business_day(&self, year: i32, month: Month, day: u8) -> bool {
+        self.is_business_day_at(calendar::days_from_civil(year, month, day))
+    }
+
+    /// Reports whether `day` of `month` of `year` is a holiday, or the day a
+    /// holiday is observed on.
+    pub fn is_holiday(&self, year: i32, month: Month, day: u8) -> bool {
+        let days = calendar::days_from_civil(year, month, day);
+        self.holidays
+            .iter()
+            .any(|h| is_holiday(h, year, month, day, days))
+    }
+
+    /// Returns the weekend of the calendar.
+    pub fn weekend(&self) -> WeekendPolicy {
+        self.weekend
+    }
+
+    const fn builtin(holidays: &'static [Holiday]) -> Self {
+        Self {
+            holidays: Cow::Borrowed(holidays),
+            weekend: WeekendPolicy::SATURDAY_SUNDAY,
+        }
+    }
+
+    fn is_business_day_at(&self, days: i64) -> bool {
+        if self.weekend.contains(Weekday::from_unix_days(days)) {
+            return false;
+        }
+
+        let (year, month, day) = calendar::civil_from_days(days);
+        !self.is_holiday(year as i32, month, day)
+    }
+}
+
+impl Default for HolidayCalendar {
+    /// Returns [HolidayCalendar::new].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// private APIs
+
+const fn fixed(month: Month, day: u8, observed: bool) -> Holiday {
+    Holiday::Fixed {
+        month,
+        day,
+        observed,
+    }
+}
+
+const fn nth_weekday(month: Month, weekday: Weekday, n: i8) -> Holiday {
+    Holiday::NthWeekday { month, weekday, n }
+}
+
+/// Reports whether the date `day` of `month` of `year`, which is `days` days
+/// since the Unix epoch, is the holiday `h`.
+fn is_holiday(h: &Holiday, year: i32, month: Month, day: u8, days: i64) -> bool {
+    match *h {
+        Holiday::Fixed {
+            month: m,
+            day: d,
+            observed,
+        } => {
+            if (m, d) == (month, day) {
+                return true;
+            }
+            if !observed {
+                return false;
+            }
+
+            // The holiday of the next year may be observed on December 31.
+            [year, year.saturating_add(1)].into_iter().any(|y| {
+                if d > calendar::days_in(m, y) {
+                    return false;
+                }
+                let at = calendar::days_from_civil(y, m, d);
+                match Weekday::from_unix_days(at) {
+                    Weekday::Saturday => at - 1 == days,
+                    Weekday::Sunday => at + 1 == days,
+                    _ => false,
+                }
+            })
+        }
+        Holiday::NthWeekday {
+            month: m,
+            weekday,
+            n,
+        } => m == month && weekday.nth_of_month(year, month, n as i32) == Some(day),
+        Holiday::Easter { offset } => {
+            let (m, d) = calendar::easter(year);
+            calendar::days_from_civil(year, m, d) + offset as i64 == days
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Conversions between [Duration] and the PostgreSQL `INTERVAL` type, shared
+//! by the database features.
+//!
+//! Intervals are precise to the microsecond, so a duration with a finer part
+//! fails rather than being silently truncated. Days count as 24 hours, while
+//! months have no fixed length and fail.
+
+use std::error::Error;
+
+use crate::{Duration, DurationOutOfRangeError, HOUR, MICROSECOND};
+
+/// Returns the Duration of an interval of `months`, `days` and
+/// `microseconds`.
+pub(crate) fn from_interval(
+    months: i32,
+    days: i32,
+    microseconds: i64,
+) -> Result<Duration, Box<dyn Error + Send + Sync>> {
+    if months != 0 {
+        return Err("PostgreSQL `INTERVAL` with months has no fixed duration".into());
+    }
+
+    let d = (days as i64)
+        .checked_mul(24 * HOUR.0)
+        .zip(microseconds.checked_mul(MICROSECOND.0))
+        .and_then(|(days, micros)| days.checked_add(micros))
+        .ok_or(DurationOutOfRangeError)?;
+
+    Ok(Duration(d))
+}
+
+/// Returns `d` as the microseconds of an interval.
+pub(crate) fn to_microseconds(d: Duration) -> Result<i64, Box<dyn Error + Send + Sync>> {
+    if d.0 % MICROSECOND.0 != 0 {
+        return Err("PostgreSQL `INTERVAL` does not support nanoseconds precision".into());
+    }
+
+    Ok(d.0 / MICROSECOND.0)
+}
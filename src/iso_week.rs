@@ -0,0 +1,145 @@
+use core::cmp::Ordering;
+use core::fmt::{self, Display};
+
+use crate::{calendar, Month, Weekday};
+
+/// An IsoWeekDate is a date of the ISO 8601 week-numbering calendar, such as
+/// 2024-W01-1, made of a week-numbering year, a week from 1 to 52 or 53, and a
+/// day of the week.
+///
+/// Weeks start on Monday, and week 1 is the week holding the first Thursday of
+/// the year, so the first and last few days of a calendar year may belong to
+/// a week of the neighboring week-numbering year.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/iso_week_date.rs")]
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IsoWeekDate {
+    year: i32,
+    week: u8,
+    weekday: Weekday,
+}
+
+impl IsoWeekDate {
+    /// Returns the week date of `weekday` in `week` of the week-numbering
+    /// `year`, or `None` if the year has no such week.
+    pub fn new(year: i32, week: u8, weekday: Weekday) -> Option<Self> {
+        (1..=Self::weeks_in_year(year))
+            .contains(&week)
+            .then_some(Self {
+                year,
+                week,
+                weekday,
+            })
+    }
+
+    /// Returns the week date of the calendar date `day` of `month` of `year`.
+    /// It returns `None` if the month has no such day, or if the
+    /// week-numbering year is out of the range of `i32`.
+    pub fn from_calendar_date(year: i32, month: Month, day: u8) -> Option<Self> {
+        if day == 0 || day > calendar::days_in(month, year) {
+            return None;
+        }
+
+        let weekday = Weekday::from_unix_days(calendar::days_from_civil(year, month, day));
+        let ordinal = (calendar::days_before(month, year) + day as u16) as i32;
+        let week = (ordinal - weekday.to_iso() as i32 + 10) / 7;
+
+        let (year, week) = match week {
+            0 => {
+                let year = year.checked_sub(1)?;
+                (year, Self::weeks_in_year(year))
+            }
+            53 if Self::weeks_in_year(year) == 52 => (year.checked_add(1)?, 1),
+            _ => (year, week as u8),
+        };
+        Some(Self {
+            year,
+            week,
+            weekday,
+        })
+    }
+
+    /// Returns the calendar date of the week date as its year, month and day.
+    ///
+    /// # Panics
+    /// This function panics if the calendar year is out of the range of `i32`,
+    /// which only happens for the first week of `i32::MIN` and the last week
+    /// of `i32::MAX`.
+    pub fn to_calendar_date(&self) -> (i32, Month, u8) {
+        let (year, month, day) = calendar::civil_from_days(self.to_unix_days());
+        let year = i32::try_from(year).expect("year out of range");
+        (year, month, day)
+    }
+
+    /// Returns the number of days since the Unix epoch, 1970-01-01, of the
+    /// week date, negative for days before it.
+    pub fn to_unix_days(&self) -> i64 {
+        // January 4 always falls in week 1.
+        let jan4 = calendar::days_from_civil(self.year, Month::January, 4);
+        let monday = jan4 - Weekday::from_unix_days(jan4).to_iso() as i64 + 1;
+
+        monday + (self.week as i64 - 1) * 7 + self.weekday.to_iso() as i64 - 1
+    }
+
+    /// Returns the week, from 1 to 52 or 53.
+    pub fn week(&self) -> u8 {
+        self.week
+    }
+
+    /// Returns the day of the week.
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Returns the number of weeks of the week-numbering `year`, 53 for years
+    /// starting on a Thursday, or on a Wednesday for leap years, and 52
+    /// otherwise.
+    pub fn weeks_in_year(year: i32) -> u8 {
+        let jan1 = calendar::days_from_civil(year, Month::January, 1);
+        match Weekday::from_unix_days(jan1) {
+            Weekday::Thursday => 53,
+            Weekday::Wednesday if calendar::is_leap(year) => 53,
+            _ => 52,
+        }
+    }
+
+    /// Returns the week-numbering year, which differs from the calendar year
+    /// for days of the first and last weeks falling in the neighboring year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+}
+
+impl Display for IsoWeekDate {
+    /// Writes the week date in the ISO 8601 extended form "2024-W01-1" to `f`,
+    /// with the year padded to 4 digits and days numbered from Monday = 1.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year < 0 {
+            f.write_str("-")?;
+        }
+        write!(
+            f,
+            "{:04}-W{:02}-{}",
+            self.year.unsigned_abs(),
+            self.week,
+            self.weekday.to_iso()
+        )
+    }
+}
+
+impl Ord for IsoWeekDate {
+    /// Orders week dates chronologically, with weeks starting on Monday.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |v: &Self| (v.year, v.week, v.weekday.to_iso());
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for IsoWeekDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,46 @@
+//! Conversions between the types of this crate and the corresponding ones of
+//! jiff, behind the `jiff` feature.
+
+use ::jiff::{SignedDuration, Span};
+
+use crate::{Duration, DurationOutOfRangeError};
+
+impl From<Duration> for SignedDuration {
+    fn from(value: Duration) -> Self {
+        SignedDuration::from_nanos(value.0)
+    }
+}
+
+impl TryFrom<SignedDuration> for Duration {
+    type Error = DurationOutOfRangeError;
+
+    /// Converts a jiff duration into a Duration, failing if it's out of the
+    /// range of Duration, which is narrower than jiff's.
+    fn try_from(value: SignedDuration) -> Result<Self, Self::Error> {
+        i64::try_from(value.as_nanos())
+            .map(Duration)
+            .map_err(|_| DurationOutOfRangeError)
+    }
+}
+
+impl From<Duration> for Span {
+    /// Converts a Duration into a span of seconds and smaller units.
+    fn from(value: Duration) -> Self {
+        // Spans hold about 20,000 years of seconds, far more than a Duration.
+        Span::try_from(SignedDuration::from(value))
+            .expect("duration should be in the range of span")
+    }
+}
+
+impl TryFrom<Span> for Duration {
+    type Error = DurationOutOfRangeError;
+
+    /// Converts a span into a Duration, failing if it has units of days or
+    /// larger, whose length depends on a relative date, or if it's out of the
+    /// range of Duration.
+    fn try_from(value: Span) -> Result<Self, Self::Error> {
+        SignedDuration::try_from(value)
+            .map_err(|_| DurationOutOfRangeError)
+            .and_then(Duration::try_from)
+    }
+}
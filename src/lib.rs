@@ -1,9 +1,55 @@
+#![allow(clippy::needless_doctest_main)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+pub mod calendar;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "clap")]
+mod clap;
+mod datetime;
+#[cfg(feature = "diesel")]
+mod diesel;
 mod duration;
 mod errors;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod holiday;
+#[cfg(any(feature = "diesel", feature = "postgres-types", feature = "sqlx"))]
+mod interval;
+mod iso_week;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "miette")]
+mod miette;
 mod month;
+mod period;
+#[cfg(feature = "postgres-types")]
+mod postgres_types;
+#[cfg(feature = "prost")]
+mod prost;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "time03")]
+mod time03;
 mod weekday;
 
+#[cfg(feature = "clap")]
+pub use self::clap::DurationValueParser;
+pub use datetime::*;
 pub use duration::*;
 pub use errors::*;
+pub use holiday::*;
+pub use iso_week::*;
 pub use month::*;
+pub use period::*;
 pub use weekday::*;
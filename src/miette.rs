@@ -0,0 +1,46 @@
+//! Diagnostics of the parse errors of this crate for miette, behind the
+//! `miette` feature.
+//!
+//! The errors don't keep their input, which is attached to reports with
+//! [Report::with_source_code](::miette::Report::with_source_code) to get them
+//! rendered with the failing span pointed at.
+
+use core::fmt::Display;
+
+use ::miette::{Diagnostic, LabeledSpan};
+
+use crate::DurationParseError;
+
+impl Diagnostic for DurationParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let code = match self {
+            Self::Invalid { .. } => "time::invalid_duration",
+            Self::MissUnit { .. } => "time::missing_unit",
+            Self::Overflow { .. } => "time::duration_overflow",
+            Self::UnknownUnit { .. } => "time::unknown_unit",
+        };
+        Some(Box::new(code))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::UnknownUnit {
+                suggestion: Some(v),
+                ..
+            } => Some(Box::new(format!("did you mean \"{v}\"?"))),
+            _ => None,
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = match self {
+            Self::Invalid { offset } => LabeledSpan::at_offset(*offset, "here"),
+            Self::MissUnit { offset } => LabeledSpan::at_offset(*offset, "expected a unit"),
+            Self::Overflow { offset } => LabeledSpan::at_offset(*offset, "out of range"),
+            Self::UnknownUnit { offset, len, .. } => {
+                LabeledSpan::at(*offset..*offset + *len, "unknown unit")
+            }
+        };
+        Some(Box::new(core::iter::once(label)))
+    }
+}
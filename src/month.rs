@@ -1,9 +1,19 @@
-use std::fmt::Display;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
+
+use crate::{calendar, MonthOutOfRangeError};
 
 /// A Month specifies a month of the year (January = 1, ...).
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/month_numbers.rs")]
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(i32)]
 pub enum Month {
-    January,
+    January = 1,
     February,
     March,
     April,
@@ -18,10 +28,70 @@ pub enum Month {
 }
 
 impl Month {
+    pub(crate) const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
+    /// Returns the three-letter English abbreviation of the month ("Jan",
+    /// "Feb", ...), as used by RFC 1123 and asctime.
+    pub fn abbr(&self) -> &'static str {
+        match self {
+            Month::January => "Jan",
+            Month::February => "Feb",
+            Month::March => "Mar",
+            Month::April => "Apr",
+            Month::May => "May",
+            Month::June => "Jun",
+            Month::July => "Jul",
+            Month::August => "Aug",
+            Month::September => "Sep",
+            Month::October => "Oct",
+            Month::November => "Nov",
+            Month::December => "Dec",
+        }
+    }
+
+    /// Returns the ordinal days within `year` of the first and last days of
+    /// the month, counting from 1 for January 1, e.g. (61, 91) for March of a
+    /// leap year.
+    pub fn bounds(&self, year: i32) -> (u16, u16) {
+        let first = calendar::days_before(*self, year) + 1;
+        let last = first + calendar::days_in(*self, year) as u16 - 1;
+        (first, last)
+    }
+
+    /// Returns an iterator over the months from January through December,
+    /// which can also run backward.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../examples/month_iter.rs")]
+    /// ```
+    pub fn iter() -> core::array::IntoIter<Month, 12> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the quarter of the year the month falls in, from 1 for
+    /// January through March to 4 for October through December.
+    pub fn quarter(&self) -> u8 {
+        (*self as i32 - 1) as u8 / 3 + 1
+    }
+
     /// Returns the English name of the month ("January", "February", ...).
     #[deprecated(since = "0.1.0", note = "use `to_string` instead")]
     pub fn string(&self) -> String {
-        self.to_string()
+        format!("{self}")
     }
 }
 
@@ -45,8 +115,38 @@ impl AsRef<str> for Month {
 }
 
 impl Display for Month {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let s = self.as_ref();
         f.pad(s)
     }
 }
+
+impl From<Month> for i32 {
+    /// Converts a month into its number, with January = 1.
+    fn from(value: Month) -> Self {
+        value as i32
+    }
+}
+
+impl TryFrom<i32> for Month {
+    type Error = MonthOutOfRangeError;
+
+    /// Converts a month number into a month, with January = 1, failing if
+    /// it's out of [1, 12].
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            1..=12 => Ok(Self::ALL[value as usize - 1]),
+            _ => Err(MonthOutOfRangeError),
+        }
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = MonthOutOfRangeError;
+
+    /// Converts a month number into a month, with January = 1, failing if
+    /// it's out of [1, 12].
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::try_from(value as i32)
+    }
+}
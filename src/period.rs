@@ -0,0 +1,358 @@
+use alloc::string::String;
+use core::fmt::{self, Display, Write};
+use core::ops::{Add, Mul, Neg, Sub};
+use core::str::FromStr;
+
+use crate::{offset_of, PeriodParseError};
+
+/// A Period is an amount of calendar time in years, months and days, such as
+/// "1y2mo3d". Unlike a [Duration](crate::Duration), it isn't a fixed number of
+/// nanoseconds, as months and years vary in length, and days may too across
+/// daylight saving time transitions.
+///
+/// The components are kept apart and may have different signs. Adding periods
+/// adds their components, and [Period::normalized] carries months into years.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/period.rs")]
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Period {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+impl Period {
+    /// The zero period.
+    pub const ZERO: Period = Period::new(0, 0, 0);
+
+    /// Creates a period of the given years, months and days.
+    pub const fn new(years: i32, months: i32, days: i32) -> Self {
+        Self {
+            years,
+            months,
+            days,
+        }
+    }
+
+    /// Returns `self + rhs`, adding the components, or `None` if any of them
+    /// overflows.
+    pub const fn checked_add(&self, rhs: Self) -> Option<Self> {
+        match (
+            self.years.checked_add(rhs.years),
+            self.months.checked_add(rhs.months),
+            self.days.checked_add(rhs.days),
+        ) {
+            (Some(years), Some(months), Some(days)) => Some(Self::new(years, months, days)),
+            _ => None,
+        }
+    }
+
+    /// Returns `self * rhs`, multiplying the components, or `None` if any of
+    /// them overflows.
+    pub const fn checked_mul(&self, rhs: i32) -> Option<Self> {
+        match (
+            self.years.checked_mul(rhs),
+            self.months.checked_mul(rhs),
+            self.days.checked_mul(rhs),
+        ) {
+            (Some(years), Some(months), Some(days)) => Some(Self::new(years, months, days)),
+            _ => None,
+        }
+    }
+
+    /// Returns `-self`, negating the components, or `None` if any of them is
+    /// `i32::MIN`.
+    pub const fn checked_neg(&self) -> Option<Self> {
+        match (
+            self.years.checked_neg(),
+            self.months.checked_neg(),
+            self.days.checked_neg(),
+        ) {
+            (Some(years), Some(months), Some(days)) => Some(Self::new(years, months, days)),
+            _ => None,
+        }
+    }
+
+    /// Returns `self - rhs`, subtracting the components, or `None` if any of
+    /// them overflows.
+    pub const fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        match (
+            self.years.checked_sub(rhs.years),
+            self.months.checked_sub(rhs.months),
+            self.days.checked_sub(rhs.days),
+        ) {
+            (Some(years), Some(months), Some(days)) => Some(Self::new(years, months, days)),
+            _ => None,
+        }
+    }
+
+    /// Returns true if all the components of the period are zero.
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+
+    /// Returns the period with every 12 months carried into a year, so that
+    /// months fall in (-12, 12) and have the sign of the years, e.g. "1y14mo"
+    /// becomes "2y2mo" and "1y-2mo" becomes "10mo". Days are left alone since
+    /// months don't have a fixed number of them.
+    ///
+    /// # Panics
+    /// This function panics if the years overflow.
+    pub fn normalized(&self) -> Self {
+        let months = self.total_months();
+        let years = i32::try_from(months / 12).expect("period overflow");
+
+        Self::new(years, (months % 12) as i32, self.days)
+    }
+
+    /// Parses an ISO 8601 period, such as "P1Y2M3D", "P2W" or "-P1M", in
+    /// either case.
+    ///
+    /// [FromStr] accepts these along with the short form "1y2mo3d".
+    pub fn parse_iso8601<S>(s: S) -> Result<Self, PeriodParseError>
+    where
+        S: AsRef<str>,
+    {
+        let input = s.as_ref().as_bytes();
+        let i = matches!(input.first(), Some(b'-' | b'+')) as usize;
+        match input.get(i) {
+            Some(b'P' | b'p') => parse_period(input),
+            _ => Err(PeriodParseError::Invalid { offset: i }),
+        }
+    }
+
+    /// Returns a string representing the period in the ISO 8601 form
+    /// `P1Y2M3D`. Zero components are omitted, and the zero period formats
+    /// as `P0D`. A period whose first non-zero component is negative is
+    /// prefixed with '-', negating all its components, e.g. `-P1Y2M`.
+    pub fn to_iso8601(&self) -> String {
+        let mut s = String::new();
+        // Writing into a string never fails.
+        let _ = write_period(&mut s, self, "P", ["Y", "M", "D"], "0D");
+        s
+    }
+
+    /// Returns the years and months of the period as a number of months.
+    pub const fn total_months(&self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+}
+
+impl Add for Period {
+    type Output = Self;
+
+    /// Adds the components of `rhs` to those of the period.
+    ///
+    /// # Panics
+    /// This function panics if any component overflows. Use
+    /// [Period::checked_add] to handle it instead.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("period overflow")
+    }
+}
+
+impl Display for Period {
+    /// Writes the period in the short form "1y2mo3d" to `f`. Zero components
+    /// are omitted, and the zero period formats as "0d". A period whose first
+    /// non-zero component is negative is prefixed with '-', negating all its
+    /// components, e.g. "-1y2mo".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_period(f, self, "", ["y", "mo", "d"], "0d")
+    }
+}
+
+impl FromStr for Period {
+    type Err = PeriodParseError;
+
+    /// Parses a period in the short form, such as "1y2mo3d", "2w" or
+    /// "-1y-6mo", or in the ISO 8601 form, such as "P1Y2M3D".
+    ///
+    /// Units are "y", "mo", "w" and "d", or "Y", "M", "W" and "D" after "P",
+    /// appearing at most once each in this order. The ISO 8601 form is case
+    /// insensitive, such as "p1y2m". A week is 7 days. A leading
+    /// sign applies to the whole period, and each component may carry its
+    /// own sign too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "0" {
+            return Ok(Self::ZERO);
+        }
+
+        parse_period(s.as_bytes())
+    }
+}
+
+impl Mul<i32> for Period {
+    type Output = Self;
+
+    /// Multiplies the components of the period by `rhs`.
+    ///
+    /// # Panics
+    /// This function panics if any component overflows. Use
+    /// [Period::checked_mul] to handle it instead.
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.checked_mul(rhs).expect("period overflow")
+    }
+}
+
+impl Neg for Period {
+    type Output = Self;
+
+    /// Negates the components of the period.
+    ///
+    /// # Panics
+    /// This function panics if any component overflows. Use
+    /// [Period::checked_neg] to handle it instead.
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("period overflow")
+    }
+}
+
+impl Sub for Period {
+    type Output = Self;
+
+    /// Subtracts the components of `rhs` from those of the period.
+    ///
+    /// # Panics
+    /// This function panics if any component overflows. Use
+    /// [Period::checked_sub] to handle it instead.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("period overflow")
+    }
+}
+
+// private APIs
+
+/// Units of the short form along with their component and multiplier, in the
+/// order they must appear.
+const SHORT_UNITS: [(&str, usize, i64); 4] = [("y", 0, 1), ("mo", 1, 1), ("w", 2, 7), ("d", 2, 1)];
+/// Designators of the ISO 8601 form, as [SHORT_UNITS].
+const ISO_UNITS: [(&str, usize, i64); 4] = [("Y", 0, 1), ("M", 1, 1), ("W", 2, 7), ("D", 2, 1)];
+
+/// Parses `input` in the short or the ISO 8601 form, the latter being told
+/// apart by the 'P' following the optional sign.
+fn parse_period(input: &[u8]) -> Result<Period, PeriodParseError> {
+    let mut s = input;
+    let invalid = |s: &[u8]| PeriodParseError::Invalid {
+        offset: offset_of(input, s),
+    };
+
+    // The leading sign applies to each component along with its own.
+    let neg = match s.first() {
+        Some(b'-') => {
+            s = &s[1..];
+            -1
+        }
+        Some(b'+') => {
+            s = &s[1..];
+            1
+        }
+        _ => 1,
+    };
+
+    let iso = matches!(s.first(), Some(b'P' | b'p'));
+    let units = if iso {
+        s = &s[1..];
+        &ISO_UNITS
+    } else {
+        &SHORT_UNITS
+    };
+
+    let mut components = [0i32; 3];
+    let mut next = 0;
+    if s.is_empty() {
+        return Err(invalid(s));
+    }
+    while !s.is_empty() {
+        let start = s;
+
+        let sign = match s.first() {
+            Some(b'-') => {
+                s = &s[1..];
+                -1
+            }
+            Some(b'+') => {
+                s = &s[1..];
+                1
+            }
+            _ => 1,
+        };
+
+        let digits = s.iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return Err(invalid(s));
+        }
+        let overflow = PeriodParseError::Overflow {
+            offset: offset_of(input, start),
+        };
+        let mut v = 0i64;
+        for c in &s[..digits] {
+            v = v
+                .checked_mul(10)
+                .and_then(|v| v.checked_add((c - b'0') as i64))
+                .ok_or(overflow)?;
+        }
+        s = &s[digits..];
+
+        // The short form has multi-letter units, while ISO 8601 designators
+        // are single letters.
+        let n = if iso {
+            s.len().min(1)
+        } else {
+            s.iter().take_while(|c| c.is_ascii_alphabetic()).count()
+        };
+        let unit = &s[..n];
+        let found = units[next..].iter().position(|u| {
+            if iso {
+                u.0.as_bytes().eq_ignore_ascii_case(unit)
+            } else {
+                u.0.as_bytes() == unit
+            }
+        });
+        let i = match found {
+            Some(i) => next + i,
+            None => return Err(invalid(s)),
+        };
+        next = i + 1;
+        s = &s[n..];
+
+        let (_, component, scale) = units[i];
+        components[component] = v
+            .checked_mul(scale * sign * neg)
+            .and_then(|v| v.checked_add(components[component] as i64))
+            .and_then(|v| i32::try_from(v).ok())
+            .ok_or(overflow)?;
+    }
+
+    let [years, months, days] = components;
+    Ok(Period::new(years, months, days))
+}
+
+/// Writes the sign of `p` if negative, `prefix`, and then the non-zero
+/// components of `p` followed by their `units` to `w`, or `zero` if there are
+/// none.
+fn write_period<W>(w: &mut W, p: &Period, prefix: &str, units: [&str; 3], zero: &str) -> fmt::Result
+where
+    W: Write,
+{
+    let components = [p.years as i64, p.months as i64, p.days as i64];
+    let sign = match components.iter().find(|&&v| v != 0) {
+        Some(&v) if v < 0 => {
+            w.write_char('-')?;
+            -1
+        }
+        Some(_) => 1,
+        None => return write!(w, "{prefix}{zero}"),
+    };
+    w.write_str(prefix)?;
+
+    for (v, unit) in components.into_iter().zip(units) {
+        if v != 0 {
+            write!(w, "{}{unit}", v * sign)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! postgres-types support for the types of this crate, behind the
+//! `postgres-types` feature.
+//!
+//! [Duration] maps to the PostgreSQL `INTERVAL` type, precise to the
+//! microsecond. Days count as 24 hours, and intervals with months fail to
+//! decode.
+
+use std::error::Error;
+
+use ::bytes::{BufMut, BytesMut};
+use ::postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{interval, Duration};
+
+impl<'a> FromSql<'a> for Duration {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        // An interval is sent as microseconds, days and months, big-endian.
+        let raw: [u8; 16] = raw
+            .try_into()
+            .map_err(|_| "invalid message length: interval size mismatch")?;
+        let microseconds = i64::from_be_bytes(raw[..8].try_into().unwrap());
+        let days = i32::from_be_bytes(raw[8..12].try_into().unwrap());
+        let months = i32::from_be_bytes(raw[12..].try_into().unwrap());
+
+        interval::from_interval(months, days, microseconds)
+    }
+
+    accepts!(INTERVAL);
+}
+
+impl ToSql for Duration {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.put_i64(interval::to_microseconds(*self)?);
+        out.put_i32(0);
+        out.put_i32(0);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INTERVAL);
+
+    to_sql_checked!();
+}
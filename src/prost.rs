@@ -0,0 +1,38 @@
+//! Conversions between the types of this crate and the protobuf well-known
+//! types of prost-types, behind the `prost` feature.
+
+use crate::{Duration, DurationOutOfRangeError, SECOND};
+
+impl From<Duration> for ::prost_types::Duration {
+    /// Converts a Duration into seconds and nanoseconds of the same sign, as
+    /// the protobuf specification requires.
+    fn from(value: Duration) -> Self {
+        Self {
+            seconds: value.0 / SECOND.0,
+            nanos: (value.0 % SECOND.0) as i32,
+        }
+    }
+}
+
+impl TryFrom<::prost_types::Duration> for Duration {
+    type Error = DurationOutOfRangeError;
+
+    /// Converts a protobuf duration into a Duration, failing if its nanos are
+    /// out of [-999999999, 999999999], if its non-zero seconds and nanos have
+    /// opposite signs, which the protobuf specification forbids, or if it's
+    /// out of the range of Duration, which is narrower.
+    fn try_from(value: ::prost_types::Duration) -> Result<Self, Self::Error> {
+        if value.nanos.unsigned_abs() >= SECOND.0 as u32
+            || value.seconds.signum() * (value.nanos.signum() as i64) < 0
+        {
+            return Err(DurationOutOfRangeError);
+        }
+
+        value
+            .seconds
+            .checked_mul(SECOND.0)
+            .and_then(|v| v.checked_add(value.nanos as i64))
+            .map(Duration)
+            .ok_or(DurationOutOfRangeError)
+    }
+}
@@ -0,0 +1,52 @@
+//! PyO3 conversions for the types of this crate, behind the `pyo3` feature.
+//!
+//! [Duration] maps to Python's `datetime.timedelta`. As a timedelta is precise
+//! to the microsecond, converting a Duration into one truncates it toward zero.
+
+use ::pyo3::exceptions::PyOverflowError;
+use ::pyo3::prelude::*;
+use ::pyo3::types::{PyDelta, PyDeltaAccess};
+
+use crate::{Duration, HOUR, MICROSECOND, SECOND};
+
+const MICROSECONDS_PER_DAY: i64 = 24 * HOUR.0 / MICROSECOND.0;
+
+impl<'py> FromPyObject<'py> for Duration {
+    /// Extracts a Duration from a `datetime.timedelta`, raising OverflowError
+    /// if it's out of the range of Duration, which is narrower.
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let delta = ob.downcast::<PyDelta>()?;
+        // A timedelta normalizes its seconds and microseconds to be positive,
+        // so that only days may be negative.
+        let d = (delta.get_days() as i128 * 24 * HOUR.0 as i128)
+            + (delta.get_seconds() as i128 * SECOND.0 as i128)
+            + (delta.get_microseconds() as i128 * MICROSECOND.0 as i128);
+
+        i64::try_from(d)
+            .map(Duration)
+            .map_err(|_| PyOverflowError::new_err("timedelta out of the range of Duration"))
+    }
+}
+
+impl IntoPy<PyObject> for Duration {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.to_object(py)
+    }
+}
+
+impl ToPyObject for Duration {
+    /// Converts a Duration into a `datetime.timedelta`, truncated toward zero
+    /// to the microsecond.
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        let us = self.0 / MICROSECOND.0;
+        let days = us.div_euclid(MICROSECONDS_PER_DAY);
+        let us = us.rem_euclid(MICROSECONDS_PER_DAY);
+        let seconds = us / (SECOND.0 / MICROSECOND.0);
+        let us = us % (SECOND.0 / MICROSECOND.0);
+
+        // Durations span about 292 years, well within the range of timedelta.
+        PyDelta::new_bound(py, days as i32, seconds as i32, us as i32, false)
+            .expect("duration should be in the range of timedelta")
+            .into()
+    }
+}
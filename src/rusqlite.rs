@@ -0,0 +1,21 @@
+//! rusqlite support for the types of this crate, behind the `rusqlite`
+//! feature.
+//!
+//! [Duration] is stored as an integer nanosecond count, which SQLite keeps
+//! exactly and can compare and sum directly.
+
+use ::rusqlite::types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::Duration;
+
+impl FromSql for Duration {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        i64::column_result(value).map(Duration)
+    }
+}
+
+impl ToSql for Duration {
+    fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0))
+    }
+}
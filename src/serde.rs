@@ -0,0 +1,262 @@
+//! Serde support for the types of this crate, behind the `serde` feature.
+//!
+//! [Month] and [Weekday] serialize as their English names, such as "January"
+//! and "Sunday", and deserialize from either their names or numbers. Formats
+//! that aren't human-readable only use their numbers, as a `u8`. The adapter
+//! modules serialize them as numbers instead, for use with
+//! `#[serde(with = "...")]`, while [duration_humantime] uses the form of the
+//! humantime crate for durations.
+//!
+//! [DurationParseError] serializes as a struct with stable field names, so
+//! that APIs can return it as a machine-readable validation error. It has a
+//! `kind` among "invalid", "missing_unit", "overflow" and "unknown_unit", a
+//! `message` and an `offset`, plus the `unit` and the optional `suggestion`
+//! for unknown units.
+//!
+//! # Example
+//! ```
+#![doc = include_str!("../examples/month_serde.rs")]
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::{Duration, DurationParseError, Month, UnitSuffix, Weekday};
+
+impl Serialize for DurationParseError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let kind = match self {
+            Self::Invalid { .. } => "invalid",
+            Self::MissUnit { .. } => "missing_unit",
+            Self::Overflow { .. } => "overflow",
+            Self::UnknownUnit { .. } => "unknown_unit",
+        };
+        let unknown = match self {
+            Self::UnknownUnit {
+                unit, suggestion, ..
+            } => Some((unit, suggestion)),
+            _ => None,
+        };
+
+        let n = if unknown.is_some() { 5 } else { 3 };
+        let mut state = serializer.serialize_struct("DurationParseError", n)?;
+        state.serialize_field("kind", kind)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("offset", &self.offset())?;
+        if let Some((unit, suggestion)) = unknown {
+            state.serialize_field("unit", unit)?;
+            state.serialize_field("suggestion", suggestion)?;
+        } else {
+            state.skip_field("unit")?;
+            state.skip_field("suggestion")?;
+        }
+        state.end()
+    }
+}
+
+impl Serialize for Month {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_ref())
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Month {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(MonthVisitor)
+        } else {
+            deserializer.deserialize_u8(MonthVisitor)
+        }
+    }
+}
+
+impl Serialize for Weekday {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_ref())
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Weekday {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(WeekdayVisitor)
+        } else {
+            deserializer.deserialize_u8(WeekdayVisitor)
+        }
+    }
+}
+
+/// Serializes a [Duration] in the form of the humantime crate, such as
+/// "2days 3h", and deserializes it with [Duration::parse_humantime], for
+/// fields migrating from `humantime_serde`.
+pub mod duration_humantime {
+    use super::*;
+
+    pub fn serialize<S>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&d.to_humantime())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Duration::parse_humantime(&s).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes a [Month] as its number, with January = 1.
+pub mod month_number {
+    use super::*;
+
+    pub fn serialize<S>(m: &Month, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(*m as i32)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Month, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_i32(MonthVisitor)
+    }
+}
+
+/// Serializes a [Weekday] as its number, with Sunday = 0.
+pub mod weekday_number {
+    use super::*;
+
+    pub fn serialize<S>(d: &Weekday, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(*d as i32)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Weekday, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_i32(WeekdayVisitor)
+    }
+}
+
+impl Serialize for UnitSuffix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct MonthVisitor;
+
+impl<'de> Visitor<'de> for MonthVisitor {
+    type Value = Month;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a month name or a number from 1 to 12")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        i32::try_from(v)
+            .ok()
+            .and_then(|v| Month::try_from(v).ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        i32::try_from(v)
+            .ok()
+            .and_then(|v| Month::try_from(v).ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Month::ALL
+            .into_iter()
+            .find(|m| m.as_ref() == v)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+struct WeekdayVisitor;
+
+impl<'de> Visitor<'de> for WeekdayVisitor {
+    type Value = Weekday;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a weekday name or a number from 0 to 6")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        i32::try_from(v)
+            .ok()
+            .and_then(|v| Weekday::try_from(v).ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        i32::try_from(v)
+            .ok()
+            .and_then(|v| Weekday::try_from(v).ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Weekday::ALL
+            .into_iter()
+            .find(|d| d.as_ref() == v)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
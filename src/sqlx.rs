@@ -0,0 +1,65 @@
+//! sqlx support for the types of this crate, behind the `sqlx` feature.
+//!
+//! [Duration] maps to the PostgreSQL `INTERVAL` type through [PgInterval],
+//! precise to the microsecond. Days count as 24 hours, and intervals with
+//! months fail to decode.
+
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::postgres::types::PgInterval;
+use ::sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+use ::sqlx::{Decode, Encode, Type};
+
+use crate::{interval, Duration};
+
+impl Type<Postgres> for Duration {
+    fn type_info() -> PgTypeInfo {
+        PgInterval::type_info()
+    }
+}
+
+impl PgHasArrayType for Duration {
+    fn array_type_info() -> PgTypeInfo {
+        PgInterval::array_type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Duration {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        PgInterval::try_from(*self)?.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        PgInterval::default().size_hint()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Duration {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Duration::try_from(PgInterval::decode(value)?)
+    }
+}
+
+impl TryFrom<Duration> for PgInterval {
+    type Error = BoxDynError;
+
+    /// Converts a Duration into an interval of microseconds, failing if the
+    /// duration isn't a whole number of microseconds.
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        Ok(Self {
+            months: 0,
+            days: 0,
+            microseconds: interval::to_microseconds(value)?,
+        })
+    }
+}
+
+impl TryFrom<PgInterval> for Duration {
+    type Error = BoxDynError;
+
+    /// Converts an interval into a Duration, counting days as 24 hours. It
+    /// fails if the interval has months or is out of the range of Duration.
+    fn try_from(value: PgInterval) -> Result<Self, Self::Error> {
+        interval::from_interval(value.months, value.days, value.microseconds)
+    }
+}
@@ -0,0 +1,22 @@
+//! Conversions between the types of this crate and the corresponding ones of
+//! the time crate 0.3, behind the `time03` feature.
+
+use crate::{Duration, DurationOutOfRangeError};
+
+impl From<Duration> for ::time03::Duration {
+    fn from(value: Duration) -> Self {
+        ::time03::Duration::nanoseconds(value.0)
+    }
+}
+
+impl TryFrom<::time03::Duration> for Duration {
+    type Error = DurationOutOfRangeError;
+
+    /// Converts a duration of the time crate into a Duration, failing if it's
+    /// out of the range of Duration, which is narrower.
+    fn try_from(value: ::time03::Duration) -> Result<Self, Self::Error> {
+        i64::try_from(value.whole_nanoseconds())
+            .map(Duration)
+            .map_err(|_| DurationOutOfRangeError)
+    }
+}
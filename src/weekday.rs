@@ -1,6 +1,16 @@
-use std::fmt::Display;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
 
-/// A Weekday specifies a day of the week.
+use crate::{calendar, Month, WeekdayOutOfRangeError};
+
+/// A Weekday specifies a day of the week (Sunday = 0, ...).
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/weekday_numbers.rs")]
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(i32)]
 pub enum Weekday {
     Sunday,
@@ -13,10 +23,115 @@ pub enum Weekday {
 }
 
 impl Weekday {
+    pub(crate) const ALL: [Weekday; 7] = [
+        Weekday::Sunday,
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+    ];
+
+    /// Returns the three-letter English abbreviation of the day ("Sun",
+    /// "Mon", ...), as used by RFC 1123 and log formats.
+    pub fn abbr(&self) -> &'static str {
+        match self {
+            Weekday::Sunday => "Sun",
+            Weekday::Monday => "Mon",
+            Weekday::Tuesday => "Tue",
+            Weekday::Wednesday => "Wed",
+            Weekday::Thursday => "Thu",
+            Weekday::Friday => "Fri",
+            Weekday::Saturday => "Sat",
+        }
+    }
+
+    /// Converts an ISO 8601 day number into a weekday, with Monday = 1 through
+    /// Sunday = 7, failing if it's out of [1, 7].
+    pub fn from_iso(v: u8) -> Result<Self, WeekdayOutOfRangeError> {
+        match v {
+            1..=6 => Ok(Self::ALL[v as usize]),
+            7 => Ok(Weekday::Sunday),
+            _ => Err(WeekdayOutOfRangeError),
+        }
+    }
+
+    /// Reports whether the day falls on the weekend of Saturday and Sunday.
+    pub fn is_weekend(&self) -> bool {
+        self.is_weekend_in(WeekendPolicy::SATURDAY_SUNDAY)
+    }
+
+    /// Reports whether the day falls on the weekend given by `policy`.
+    pub fn is_weekend_in(&self, policy: WeekendPolicy) -> bool {
+        policy.contains(*self)
+    }
+
+    /// Returns the weekday of the day `days` days since the Unix epoch,
+    /// 1970-01-01, which was a Thursday. Negative `days` count backward from
+    /// it.
+    pub const fn from_unix_days(days: i64) -> Self {
+        Self::ALL[(days.rem_euclid(7) as usize + 4) % 7]
+    }
+
+    /// Returns an iterator over the days of the week from Sunday through
+    /// Saturday, which can also run backward.
+    pub fn iter() -> core::array::IntoIter<Weekday, 7> {
+        Self::iter_from(Weekday::Sunday)
+    }
+
+    /// Returns an iterator over the days of the week starting from `start`,
+    /// such as Monday through Sunday for weeks starting on Monday.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../examples/weekday_iter_from.rs")]
+    /// ```
+    pub fn iter_from(start: Weekday) -> core::array::IntoIter<Weekday, 7> {
+        let mut days = Self::ALL;
+        days.rotate_left(start as usize);
+        days.into_iter()
+    }
+
+    /// Returns the day of the month of the `n`-th occurrence of the weekday in
+    /// `month` of `year`, counting from the end of the month for negative `n`,
+    /// e.g. the second Sunday of March for 2 or the last Friday for -1. It
+    /// returns `None` if `n` is zero or the month has fewer occurrences.
+    ///
+    /// # Example
+    /// ```
+    #[doc = include_str!("../examples/weekday_nth_of_month.rs")]
+    /// ```
+    pub fn nth_of_month(&self, year: i32, month: Month, n: i32) -> Option<u8> {
+        let first = Self::from_unix_days(calendar::days_from_civil(year, month, 1));
+        let len = calendar::days_in(month, year) as i32;
+
+        // 0-based day of the first occurrence, and number of occurrences.
+        let offset = (*self as i32 - first as i32).rem_euclid(7);
+        let count = (len - 1 - offset) / 7 + 1;
+
+        let k = match n {
+            1.. if n <= count => n - 1,
+            ..=-1 if n >= -count => count + n,
+            _ => return None,
+        };
+
+        Some((1 + offset + 7 * k) as u8)
+    }
+
     /// Returns the English name of the day ("Sunday", "Monday", ...).
     #[deprecated(since = "0.1.0", note = "use `to_string` instead")]
     pub fn string(&self) -> String {
-        self.to_string()
+        format!("{self}")
+    }
+
+    /// Returns the ISO 8601 day number of the weekday, with Monday = 1 through
+    /// Sunday = 7. Use [i32::from] for Go's numbering with Sunday = 0.
+    pub fn to_iso(&self) -> u8 {
+        match self {
+            Weekday::Sunday => 7,
+            _ => *self as u8,
+        }
     }
 }
 
@@ -35,8 +150,71 @@ impl AsRef<str> for Weekday {
 }
 
 impl Display for Weekday {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let s = self.as_ref();
         f.pad(s)
     }
 }
+
+impl From<Weekday> for i32 {
+    /// Converts a weekday into its number, with Sunday = 0 as in Go.
+    fn from(value: Weekday) -> Self {
+        value as i32
+    }
+}
+
+impl TryFrom<i32> for Weekday {
+    type Error = WeekdayOutOfRangeError;
+
+    /// Converts a day number into a weekday, with Sunday = 0 as in Go,
+    /// failing if it's out of [0, 6]. Use [Weekday::from_iso] for ISO 8601
+    /// numbering.
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0..=6 => Ok(Self::ALL[value as usize]),
+            _ => Err(WeekdayOutOfRangeError),
+        }
+    }
+}
+
+/// A WeekendPolicy specifies the set of days making up the weekend, which
+/// varies by region.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/weekday_is_weekend.rs")]
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WeekendPolicy(u8);
+
+impl WeekendPolicy {
+    /// The weekend of Friday and Saturday, as in most of the Middle East.
+    pub const FRIDAY_SATURDAY: Self = Self::new(&[Weekday::Friday, Weekday::Saturday]);
+    /// The weekend of Saturday and Sunday, as in most of the world.
+    pub const SATURDAY_SUNDAY: Self = Self::new(&[Weekday::Saturday, Weekday::Sunday]);
+    /// The weekend of Sunday only.
+    pub const SUNDAY: Self = Self::new(&[Weekday::Sunday]);
+
+    /// Returns the policy whose weekend consists of `days`.
+    pub const fn new(days: &[Weekday]) -> Self {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < days.len() {
+            mask |= 1 << days[i] as u8;
+            i += 1;
+        }
+        Self(mask)
+    }
+
+    /// Reports whether `day` is part of the weekend.
+    pub fn contains(&self, day: Weekday) -> bool {
+        self.0 & (1 << day as u8) != 0
+    }
+}
+
+impl Default for WeekendPolicy {
+    /// Returns [WeekendPolicy::SATURDAY_SUNDAY].
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
+}
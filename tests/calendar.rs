@@ -0,0 +1,120 @@
+use time::{calendar, Month, Period};
+
+#[test]
+fn add_period() {
+    let test_vector = vec![
+        (
+            (2024, Month::January, 31),
+            Period::new(0, 1, 0),
+            (2024, Month::March, 2),
+        ),
+        (
+            (2023, Month::January, 31),
+            Period::new(0, 1, 0),
+            (2023, Month::March, 3),
+        ),
+        (
+            (2024, Month::February, 29),
+            Period::new(1, 0, 0),
+            (2025, Month::March, 1),
+        ),
+        (
+            (2024, Month::March, 31),
+            Period::new(0, -1, 0),
+            (2024, Month::March, 2),
+        ),
+        (
+            (2024, Month::December, 15),
+            Period::new(0, 1, 20),
+            (2025, Month::February, 4),
+        ),
+        (
+            (2024, Month::January, 1),
+            Period::new(-1, -2, -3),
+            (2022, Month::October, 29),
+        ),
+        (
+            (2024, Month::May, 5),
+            Period::new(0, 14, 0),
+            (2025, Month::July, 5),
+        ),
+        ((2024, Month::May, 5), Period::ZERO, (2024, Month::May, 5)),
+    ];
+
+    for (i, (date, p, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, calendar::add_period(date, p), "#{i}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "year out of range")]
+fn add_period_overflow() {
+    calendar::add_period((i32::MAX, Month::December, 1), Period::new(0, 1, 0));
+}
+
+#[test]
+fn days_between() {
+    let test_vector = vec![
+        ((1970, Month::January, 1), (1970, Month::January, 1), 0),
+        ((1970, Month::January, 1), (2000, Month::March, 1), 11017),
+        ((2024, Month::March, 1), (2024, Month::February, 28), -2),
+        ((2023, Month::January, 1), (2024, Month::January, 1), 365),
+        ((2024, Month::January, 1), (2025, Month::January, 1), 366),
+    ];
+
+    for (i, (from, to, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, calendar::days_between(from, to), "#{i}");
+    }
+}
+
+#[test]
+fn period_between() {
+    let test_vector = vec![
+        (
+            (2024, Month::January, 15),
+            (2024, Month::January, 15),
+            Period::ZERO,
+        ),
+        (
+            (2024, Month::January, 15),
+            (2025, Month::March, 20),
+            Period::new(1, 2, 5),
+        ),
+        (
+            (2024, Month::January, 15),
+            (2024, Month::March, 10),
+            Period::new(0, 1, 24),
+        ),
+        (
+            (2023, Month::January, 31),
+            (2023, Month::March, 1),
+            Period::new(0, 0, 29),
+        ),
+        (
+            (2024, Month::January, 31),
+            (2024, Month::March, 2),
+            Period::new(0, 1, 0),
+        ),
+        (
+            (2024, Month::March, 20),
+            (2023, Month::January, 15),
+            Period::new(-1, -2, -5),
+        ),
+        (
+            (2024, Month::March, 10),
+            (2024, Month::January, 15),
+            Period::new(0, -1, -26),
+        ),
+        (
+            (2024, Month::December, 31),
+            (2025, Month::January, 1),
+            Period::new(0, 0, 1),
+        ),
+    ];
+
+    for (i, (from, to, want)) in test_vector.into_iter().enumerate() {
+        let got = calendar::period_between(from, to);
+        assert_eq!(want, got, "#{i}");
+        assert_eq!(to, calendar::add_period(from, got), "#{i}");
+    }
+}
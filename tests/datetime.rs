@@ -0,0 +1,234 @@
+use time::{DateTime, DateTimeParseError, Duration, Month, Period, HOUR, NANOSECOND, SECOND};
+
+fn datetime(year: i32, month: Month, day: u8, hms: (u8, u8, u8), nanosecond: u32) -> DateTime {
+    let (hour, minute, second) = hms;
+    DateTime::new(year, month, day, hour, minute, second, nanosecond).unwrap()
+}
+
+#[test]
+fn add_period() {
+    let dt = datetime(2023, Month::January, 31, (8, 0, 0), 0);
+
+    assert_eq!(
+        datetime(2023, Month::March, 3, (8, 0, 0), 0),
+        dt.add_period(Period::new(0, 1, 0))
+    );
+    assert_eq!(
+        datetime(2021, Month::December, 30, (8, 0, 0), 0),
+        dt.add_period(Period::new(-1, -1, -1))
+    );
+}
+
+#[test]
+fn checked_add() {
+    let dt = datetime(2024, Month::February, 28, (23, 0, 0), 0);
+
+    let test_vector = vec![
+        (HOUR, datetime(2024, Month::February, 29, (0, 0, 0), 0)),
+        (
+            -23 * HOUR - NANOSECOND,
+            datetime(2024, Month::February, 27, (23, 59, 59), 999_999_999),
+        ),
+        (Duration(0), dt),
+        (
+            Duration(i64::MAX),
+            datetime(2316, Month::June, 9, (22, 47, 16), 854_775_807),
+        ),
+        (
+            Duration(i64::MIN),
+            datetime(1731, Month::November, 19, (23, 12, 43), 145_224_192),
+        ),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(Some(want), dt.checked_add(d), "#{i}");
+        assert_eq!(Some(dt), want.checked_sub(d), "#{i}");
+        assert_eq!(Some(d), want.duration_since(&dt), "#{i}");
+    }
+
+    let max = datetime(i32::MAX, Month::December, 31, (23, 59, 59), 999_999_999);
+    assert_eq!(None, max.checked_add(NANOSECOND));
+    let min = datetime(i32::MIN, Month::January, 1, (0, 0, 0), 0);
+    assert_eq!(None, min.checked_sub(NANOSECOND));
+    assert_eq!(None, max.duration_since(&min));
+}
+
+#[test]
+fn days_until() {
+    let a = datetime(2024, Month::January, 31, (23, 0, 0), 0);
+    let b = datetime(2024, Month::March, 1, (1, 0, 0), 0);
+
+    assert_eq!(30, a.days_until(&b));
+    assert_eq!(-30, b.days_until(&a));
+    assert_eq!(0, a.days_until(&a));
+}
+
+#[test]
+fn from_str() {
+    let test_vector = vec![
+        (
+            "2024-03-10T02:30:00",
+            datetime(2024, Month::March, 10, (2, 30, 0), 0),
+        ),
+        (
+            "2024-03-10t02:30:00",
+            datetime(2024, Month::March, 10, (2, 30, 0), 0),
+        ),
+        (
+            "2024-03-10 02:30:00.5",
+            datetime(2024, Month::March, 10, (2, 30, 0), 500_000_000),
+        ),
+        (
+            "2024-03-10T23:59:59.000000001",
+            datetime(2024, Month::March, 10, (23, 59, 59), 1),
+        ),
+        (
+            "2024-02-29",
+            datetime(2024, Month::February, 29, (0, 0, 0), 0),
+        ),
+        (
+            "+12024-01-01",
+            datetime(12024, Month::January, 1, (0, 0, 0), 0),
+        ),
+        (
+            "-0001-12-31T00:00:00",
+            datetime(-1, Month::December, 31, (0, 0, 0), 0),
+        ),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(Ok(want), input.parse(), "#{i}");
+    }
+}
+
+#[test]
+fn from_str_error() {
+    let test_vector = vec![
+        ("", DateTimeParseError::Invalid { offset: 0 }),
+        ("24-03-10", DateTimeParseError::Invalid { offset: 2 }),
+        ("2024/03/10", DateTimeParseError::Invalid { offset: 4 }),
+        ("2024-3-10", DateTimeParseError::Invalid { offset: 6 }),
+        ("2024-13-10", DateTimeParseError::OutOfRange { offset: 5 }),
+        ("2023-02-29", DateTimeParseError::OutOfRange { offset: 8 }),
+        (
+            "2024-03-10X02:30:00",
+            DateTimeParseError::Invalid { offset: 10 },
+        ),
+        (
+            "2024-03-10T02:30",
+            DateTimeParseError::Invalid { offset: 16 },
+        ),
+        (
+            "2024-03-10T24:00:00",
+            DateTimeParseError::OutOfRange { offset: 11 },
+        ),
+        (
+            "2024-03-10T02:60:00",
+            DateTimeParseError::OutOfRange { offset: 14 },
+        ),
+        (
+            "2024-03-10T02:30:60",
+            DateTimeParseError::OutOfRange { offset: 17 },
+        ),
+        (
+            "2024-03-10T02:30:00.",
+            DateTimeParseError::Invalid { offset: 20 },
+        ),
+        (
+            "2024-03-10T02:30:00.0000000001",
+            DateTimeParseError::Invalid { offset: 29 },
+        ),
+        (
+            "2024-03-10T02:30:00Z",
+            DateTimeParseError::Invalid { offset: 19 },
+        ),
+        (
+            "2147483648-01-01",
+            DateTimeParseError::OutOfRange { offset: 0 },
+        ),
+        (
+            "-99999999999999999999-01-01",
+            DateTimeParseError::OutOfRange { offset: 1 },
+        ),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(Err(want), input.parse::<DateTime>(), "#{i}");
+    }
+}
+
+#[test]
+fn from_calendar_date() {
+    assert!(DateTime::from_calendar_date(2023, Month::February, 29).is_none());
+    assert!(DateTime::from_calendar_date(2024, Month::January, 0).is_none());
+
+    let dt = DateTime::from_calendar_date(2024, Month::January, 1).unwrap();
+    assert!(dt.with_time(24, 0, 0, 0).is_none());
+    assert!(dt.with_time(0, 0, 0, SECOND.0 as u32).is_none());
+}
+
+#[test]
+fn new() {
+    let dt = DateTime::new(2024, Month::February, 29, 23, 59, 59, 999_999_999).unwrap();
+    assert_eq!((2024, Month::February, 29), dt.to_calendar_date());
+    assert_eq!((23, 59, 59, 999_999_999), dt.time_of_day());
+
+    assert!(DateTime::new(2023, Month::February, 29, 0, 0, 0, 0).is_none());
+    assert!(DateTime::new(2024, Month::February, 29, 24, 0, 0, 0).is_none());
+    assert!(DateTime::new(2024, Month::February, 29, 0, 60, 0, 0).is_none());
+    assert!(DateTime::new(2024, Month::February, 29, 0, 0, 60, 0).is_none());
+    assert!(DateTime::new(2024, Month::February, 29, 0, 0, 0, 1_000_000_000).is_none());
+}
+
+#[test]
+fn ord() {
+    let a = datetime(2024, Month::January, 1, (23, 59, 59), 0);
+    let b = datetime(2024, Month::January, 2, (0, 0, 0), 0);
+    let c = datetime(2024, Month::January, 2, (0, 0, 0), 1);
+
+    assert!(a < b && b < c);
+}
+
+#[test]
+fn period_until() {
+    let a = datetime(2024, Month::January, 15, (23, 0, 0), 0);
+    let b = datetime(2025, Month::March, 20, (1, 0, 0), 0);
+
+    assert_eq!(Period::new(1, 2, 5), a.period_until(&b));
+    assert_eq!(Period::new(-1, -2, -5), b.period_until(&a));
+    assert_eq!(
+        b.to_calendar_date(),
+        a.add_period(a.period_until(&b)).to_calendar_date()
+    );
+}
+
+#[test]
+fn to_string() {
+    let test_vector = vec![
+        (
+            datetime(2024, Month::March, 10, (2, 30, 0), 0),
+            "2024-03-10T02:30:00",
+        ),
+        (
+            datetime(2024, Month::March, 10, (2, 30, 0), 500_000_000),
+            "2024-03-10T02:30:00.5",
+        ),
+        (
+            datetime(2024, Month::March, 10, (2, 30, 0), 1),
+            "2024-03-10T02:30:00.000000001",
+        ),
+        (
+            datetime(12, Month::January, 1, (0, 0, 0), 120),
+            "0012-01-01T00:00:00.00000012",
+        ),
+        (
+            datetime(-1, Month::December, 31, (0, 0, 0), 0),
+            "-0001-12-31T00:00:00",
+        ),
+    ];
+
+    for (i, (dt, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, dt.to_string(), "#{i}");
+        assert_eq!(Ok(dt), want.parse(), "#{i}");
+    }
+}
@@ -1,5 +1,12 @@
-use time::{Duration, HOUR, MICROSECOND, MILLISECOND, MINUTE, NANOSECOND, SECOND};
+// Lints tripped by tests written before clippy was enforced, kept as written.
+#![allow(clippy::expect_fun_call, clippy::useless_conversion)]
 
+use time::{
+    Duration, DurationFromFloatError, DurationParseError, DurationParser, UnitSet, HOUR,
+    MICROSECOND, MILLISECOND, MINUTE, NANOSECOND, SECOND,
+};
+
+// units and accessors
 #[test]
 fn hours() {
     let test_vector = vec![
@@ -63,16 +70,349 @@ fn nanoseconds() {
 }
 
 #[test]
-fn parse_duration() {
+fn seconds() {
+    let test_vector = vec![(Duration(300000000), 0.3)];
+
+    for (i, (c, expect)) in test_vector.into_iter().enumerate() {
+        assert_eq!(expect, c.seconds(), "#{i}");
+    }
+}
+
+#[cfg(feature = "days")]
+#[test]
+fn days() {
+    use time::{DAY, WEEK};
+
+    assert_eq!(24 * HOUR, DAY);
+    assert_eq!(7 * DAY, WEEK);
+    assert_eq!("168h0m0s", WEEK.to_string());
+}
+
+#[test]
+fn rounded_accessors() {
+    let test_vector = vec![
+        (Duration(0), 0, 0),
+        (Duration(499), 0, 0),
+        (Duration(500), 1, 0),
+        (Duration(-500), -1, 0),
+        (Duration(1_499_999), 1500, 1),
+        (Duration(1_500_000), 1500, 2),
+        (Duration(-1_500_000), -1500, -2),
+        (Duration(-2_499_999), -2500, -2),
+        (Duration(i64::MAX), 9223372036854776, 9223372036855),
+        (Duration(i64::MIN), -9223372036854776, -9223372036855),
+    ];
+
+    for (i, (d, want_micros, want_millis)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want_micros, d.microseconds_rounded(), "#{i}");
+        assert_eq!(want_millis, d.milliseconds_rounded(), "#{i}");
+    }
+}
+
+#[test]
+fn sign() {
+    let test_vector = vec![
+        (Duration(0), 0, true, false, false),
+        (NANOSECOND, 1, false, true, false),
+        (-NANOSECOND, -1, false, false, true),
+        (HOUR, 1, false, true, false),
+        (Duration(i64::MAX), 1, false, true, false),
+        (Duration(i64::MIN), -1, false, false, true),
+    ];
+
+    for (i, (d, signum, zero, positive, negative)) in test_vector.into_iter().enumerate() {
+        assert_eq!(signum, d.signum(), "#{i}");
+        assert_eq!(zero, d.is_zero(), "#{i}");
+        assert_eq!(positive, d.is_positive(), "#{i}");
+        assert_eq!(negative, d.is_negative(), "#{i}");
+    }
+}
+
+// constructors
+#[test]
+fn new() {
+    let test_vector = vec![
+        (0, 0, Duration(0)),
+        (1, 0, SECOND),
+        (0, 1, NANOSECOND),
+        (1, -1, SECOND - NANOSECOND),
+        (-1, 1, -SECOND + NANOSECOND),
+        (2, 1_500_000_000, 3 * SECOND + 500 * MILLISECOND),
+        (-2, -1_500_000_000, -(3 * SECOND + 500 * MILLISECOND)),
+        (9223372036, 854775807, Duration(i64::MAX)),
+        (-9223372036, -854775808, Duration(i64::MIN)),
+    ];
+
+    for (i, (secs, nanos, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, Duration::new(secs, nanos), "#{i}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "overflow in Duration::new")]
+fn new_overflow() {
+    Duration::new(9223372036, 854775808);
+}
+
+#[test]
+fn from_integers() {
+    const TIMEOUT: Duration = Duration::from_hours(2);
+    assert_eq!(2 * HOUR, TIMEOUT);
+
+    let test_vector = vec![
+        (Duration::from_hours(-3), -3 * HOUR),
+        (Duration::from_minutes(90), 90 * MINUTE),
+        (Duration::from_seconds(-5), -5 * SECOND),
+        (Duration::from_millis(1500), 1500 * MILLISECOND),
+        (Duration::from_micros(7), 7 * MICROSECOND),
+        (Duration::from_nanos(i64::MIN), Duration(i64::MIN)),
+        (Duration::from_hours(2562047), Duration(2562047 * HOUR.0)),
+        (
+            Duration::from_seconds(-9223372036),
+            Duration(-9223372036 * SECOND.0),
+        ),
+    ];
+
+    for (i, (got, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, got, "#{i}");
+    }
+}
+
+#[test]
+fn checked_from_integers() {
+    let test_vector = vec![
+        (Duration::checked_from_hours(2562047), Some(2562047 * HOUR)),
+        (Duration::checked_from_hours(2562048), None),
+        (Duration::checked_from_hours(-2562048), None),
+        (Duration::checked_from_minutes(-90), Some(-90 * MINUTE)),
+        (Duration::checked_from_minutes(i64::MAX / 60), None),
+        (
+            Duration::checked_from_seconds(9223372036),
+            Some(9223372036 * SECOND),
+        ),
+        (Duration::checked_from_seconds(9223372037), None),
+        (
+            Duration::checked_from_millis(1500),
+            Some(1500 * MILLISECOND),
+        ),
+        (Duration::checked_from_millis(i64::MIN), None),
+        (Duration::checked_from_micros(7), Some(7 * MICROSECOND)),
+        (Duration::checked_from_micros(i64::MAX / 999), None),
+    ];
+
+    for (i, (got, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, got, "#{i}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "overflow in Duration::from_hours")]
+fn from_hours_overflow() {
+    Duration::from_hours(2562048);
+}
+
+#[test]
+#[should_panic(expected = "overflow in Duration::from_seconds")]
+fn from_seconds_overflow() {
+    Duration::from_seconds(i64::MIN / 1000);
+}
+
+#[test]
+fn from_millis_f64() {
+    let test_vector = vec![
+        (0.0, 0.into()),
+        (1.5, 1500 * MICROSECOND),
+        (-1.5, -1500 * MICROSECOND),
+        (0.0000015, 2.into()),
+        (-0.0000015, (-2).into()),
+        (86400000.0, 24 * HOUR),
+    ];
+
+    for (i, (f, want)) in test_vector.into_iter().enumerate() {
+        let want: Duration = want;
+        let got = Duration::from_millis_f64(f).unwrap_or_else(|_| panic!("#{i}"));
+        assert_eq!(want, got, "#{i}");
+    }
+
+    assert_eq!(
+        Err(DurationFromFloatError::NaN),
+        Duration::from_millis_f64(f64::NAN)
+    );
+    assert_eq!(
+        Err(DurationFromFloatError::Overflow),
+        Duration::from_millis_f64(1e13)
+    );
+}
+
+#[test]
+fn from_secs_f64() {
+    let test_vector = vec![
+        (0.0, 0.into()),
+        (1.0, SECOND),
+        (-1.0, -SECOND),
+        (0.3, 300 * MILLISECOND),
+        (0.0000000015, 2.into()),
+        (-0.0000000015, (-2).into()),
+        (-86400.0, -24 * HOUR),
+    ];
+
+    for (i, (f, want)) in test_vector.into_iter().enumerate() {
+        let want: Duration = want;
+        let got = Duration::from_secs_f64(f).unwrap_or_else(|_| panic!("#{i}"));
+        assert_eq!(want, got, "#{i}");
+    }
+
+    let errs = vec![
+        (f64::NAN, DurationFromFloatError::NaN),
+        (f64::INFINITY, DurationFromFloatError::Overflow),
+        (f64::NEG_INFINITY, DurationFromFloatError::Overflow),
+        (9.3e9, DurationFromFloatError::Overflow),
+        (-1e10, DurationFromFloatError::Overflow),
+    ];
+
+    for (i, (f, want)) in errs.into_iter().enumerate() {
+        let got = Duration::from_secs_f64(f).expect_err("want error");
+        assert_eq!(want.to_string(), got.to_string(), "#{i}");
+    }
+}
+
+#[test]
+fn duration_macro() {
+    const TIMEOUT: Duration = time::duration!("1h30m");
+    assert_eq!(HOUR + 30 * MINUTE, TIMEOUT);
+
+    assert_eq!(Duration(0), time::duration!("-0"));
+    assert_eq!(1500 * MILLISECOND, time::duration!("1.5s"));
+    assert_eq!(-MICROSECOND, time::duration!("-1µs"));
+    assert_eq!(
+        Duration(i64::MIN),
+        time::duration!("-9223372036854775808ns")
+    );
+
+    // Agrees with parse_duration.
     for (i, c) in PARSE_TESTS.iter().enumerate() {
-        let got: Duration = c
-            .input
-            .parse()
-            .expect(&format!("#{} parse '{}'", i, c.input));
+        let got = Duration::const_parse(c.input);
         assert_eq!(c.want, got, "#{} parse '{}'", i, c.input);
     }
 }
 
+// arithmetic
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "overflow")]
+fn add_overflow() {
+    let _ = Duration(i64::MAX) + NANOSECOND;
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "overflow")]
+fn sub_overflow() {
+    let _ = Duration(i64::MIN) - NANOSECOND;
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "overflow")]
+fn mul_overflow() {
+    let _ = Duration(i64::MAX) * 2;
+}
+
+#[test]
+fn wrapping_ops() {
+    let max = Duration(i64::MAX);
+    let min = Duration(i64::MIN);
+
+    assert_eq!(min, max.wrapping_add(NANOSECOND));
+    assert_eq!(max, min.wrapping_sub(NANOSECOND));
+    assert_eq!(Duration(-2), max.wrapping_mul(2));
+    assert_eq!(2 * HOUR, HOUR.wrapping_add(HOUR));
+    assert_eq!(-HOUR, HOUR.wrapping_sub(2 * HOUR));
+    assert_eq!(6 * HOUR, HOUR.wrapping_mul(6));
+}
+
+#[test]
+fn mul_f64() {
+    let test_vector = vec![
+        (SECOND, 1.5, 1500 * MILLISECOND),
+        (SECOND, -0.5, -500 * MILLISECOND),
+        (3.into(), 0.5, 2.into()),
+        ((-3).into(), 0.5, (-2).into()),
+        (HOUR, 1e300, i64::MAX.into()),
+        (HOUR, -1e300, i64::MIN.into()),
+        (HOUR, f64::NAN, 0.into()),
+        (i64::MAX.into(), 1.0, i64::MAX.into()),
+    ];
+
+    for (i, (d, f, want)) in test_vector.into_iter().enumerate() {
+        let want: Duration = want;
+        assert_eq!(want, d.mul_f64(f), "#{i}");
+    }
+}
+
+#[test]
+fn div_f64() {
+    let test_vector = vec![
+        (SECOND, 2.0, 500 * MILLISECOND),
+        (SECOND, -2.0, -500 * MILLISECOND),
+        (3.into(), 2.0, 2.into()),
+        ((-3).into(), 2.0, (-2).into()),
+        (SECOND, 0.0, i64::MAX.into()),
+        (-SECOND, 0.0, i64::MIN.into()),
+        (SECOND, f64::INFINITY, 0.into()),
+        (0.into(), 0.0, 0.into()),
+    ];
+
+    for (i, (d, f, want)) in test_vector.into_iter().enumerate() {
+        let want: Duration = want;
+        assert_eq!(want, d.div_f64(f), "#{i}");
+    }
+}
+
+#[test]
+fn div_duration_f64() {
+    let test_vector = vec![
+        (15 * MINUTE, HOUR, 0.25, 25.0),
+        (-SECOND, 4 * SECOND, -0.25, -25.0),
+        (3 * HOUR, HOUR, 3.0, 300.0),
+        (Duration(0), HOUR, 0.0, 0.0),
+        (SECOND, Duration(0), f64::INFINITY, f64::INFINITY),
+        (-SECOND, Duration(0), f64::NEG_INFINITY, f64::NEG_INFINITY),
+    ];
+
+    for (i, (d, total, want, want_percent)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.div_duration_f64(total), "#{i}");
+        assert_eq!(want_percent, d.percent_of(total), "#{i}");
+    }
+
+    assert!(Duration(0).div_duration_f64(Duration(0)).is_nan());
+}
+
+#[test]
+fn mul_ratio() {
+    let test_vector = vec![
+        (HOUR, 3, 4, 45 * MINUTE),
+        (SECOND, 1, 3, Duration(333_333_333)),
+        (-SECOND, 1, 3, Duration(-333_333_333)),
+        (SECOND, -2, 3, Duration(-666_666_666)),
+        (Duration(i64::MAX), 3, 3, Duration(i64::MAX)),
+        (Duration(i64::MAX), 2, 3, Duration(6148914691236517204)),
+        (Duration(i64::MAX), 2, 1, Duration(i64::MAX)),
+        (Duration(i64::MAX), -2, 1, Duration(i64::MIN)),
+        (Duration(i64::MIN), -1, 1, Duration(i64::MAX)),
+    ];
+
+    for (i, (d, numer, denom, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.mul_ratio(numer, denom), "#{i}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "zero denominator in Duration::mul_ratio")]
+fn mul_ratio_zero_denominator() {
+    SECOND.mul_ratio(1, 0);
+}
+
 #[test]
 fn round() {
     struct Case {
@@ -123,52 +463,69 @@ fn round() {
 }
 
 #[test]
-fn seconds() {
-    let test_vector = vec![(Duration(300000000), 0.3)];
+fn round_with() {
+    use time::RoundingMode::*;
 
-    for (i, (c, expect)) in test_vector.into_iter().enumerate() {
-        assert_eq!(expect, c.seconds(), "#{i}");
+    let test_vector = vec![
+        (0.into(), SECOND, Ceil, 0.into()),
+        (MINUTE, 0.into(), Floor, MINUTE),
+        (MINUTE, -SECOND, Ceil, MINUTE),
+        (2 * MINUTE + 10 * SECOND, MINUTE, Ceil, 3 * MINUTE),
+        (2 * MINUTE + 10 * SECOND, MINUTE, Floor, 2 * MINUTE),
+        (2 * MINUTE + 10 * SECOND, MINUTE, HalfEven, 2 * MINUTE),
+        (
+            2 * MINUTE + 10 * SECOND,
+            MINUTE,
+            HalfAwayFromZero,
+            2 * MINUTE,
+        ),
+        (2 * MINUTE + 30 * SECOND, MINUTE, HalfEven, 2 * MINUTE),
+        (3 * MINUTE + 30 * SECOND, MINUTE, HalfEven, 4 * MINUTE),
+        (
+            2 * MINUTE + 30 * SECOND,
+            MINUTE,
+            HalfAwayFromZero,
+            3 * MINUTE,
+        ),
+        (-2 * MINUTE - 10 * SECOND, MINUTE, Ceil, -2 * MINUTE),
+        (-2 * MINUTE - 10 * SECOND, MINUTE, Floor, -3 * MINUTE),
+        (-2 * MINUTE - 30 * SECOND, MINUTE, HalfEven, -2 * MINUTE),
+        (-3 * MINUTE - 30 * SECOND, MINUTE, HalfEven, -4 * MINUTE),
+        (
+            -2 * MINUTE - 30 * SECOND,
+            MINUTE,
+            HalfAwayFromZero,
+            -3 * MINUTE,
+        ),
+        (-2 * MINUTE - 50 * SECOND, MINUTE, HalfEven, -3 * MINUTE),
+        (i64::MAX.into(), SECOND, Ceil, i64::MAX.into()),
+        (i64::MIN.into(), SECOND, Floor, i64::MIN.into()),
+        (
+            i64::MAX.into(),
+            HOUR,
+            Floor,
+            (i64::MAX - i64::MAX % HOUR.0).into(),
+        ),
+    ];
+
+    for (i, (d, m, mode, want)) in test_vector.into_iter().enumerate() {
+        let (d, want): (Duration, Duration) = (d, want);
+        assert_eq!(want, d.round_with(m, mode), "#{i}");
     }
-}
 
-#[test]
-fn to_string() {
-    let test_vector: Vec<(&str, Duration)> = vec![
-        ("0s", 0.into()),
-        ("1ns", 1 * NANOSECOND),
-        ("1.1µs", 1100 * NANOSECOND),
-        ("2.2ms", 2200 * MICROSECOND),
-        ("3.3s", 3300 * MILLISECOND),
-        ("4m5s", 4 * MINUTE + 5 * SECOND),
-        ("4m5.001s", 4 * MINUTE + 5001 * MILLISECOND),
-        ("5h6m7.001s", 5 * HOUR + 6 * MINUTE + 7001 * MILLISECOND),
-        ("8m0.000000001s", 8 * MINUTE + 1 * NANOSECOND),
-        ("2562047h47m16.854775807s", i64::MAX.into()),
-        ("-2562047h47m16.854775808s", i64::MIN.into()),
+    // HalfAwayFromZero agrees with round.
+    for (i, d) in [
+        2 * MINUTE + 30 * SECOND,
+        -2 * MINUTE - 30 * SECOND,
+        Duration(i64::MAX),
+        Duration(i64::MIN),
+        Duration((3 << 61) - 1),
     ]
     .into_iter()
-    .map(|(s, d)| (s, d.into()))
-    .collect();
-
-    for (i, (expect, d)) in test_vector.into_iter().enumerate() {
-        assert_eq!(
-            expect,
-            d.to_string(),
-            "#{} Duration({}).to_string()",
-            i,
-            d.0
-        );
-
-        if d.0 > 0 {
-            let expect = format!("-{expect}");
-            let d = Duration(-d.0);
-            assert_eq!(
-                expect,
-                d.to_string(),
-                "#{} Duration({}).to_string()",
-                i,
-                -d.0
-            );
+    .enumerate()
+    {
+        for m in [MINUTE, Duration(3 << 61), Duration(5e18 as i64)] {
+            assert_eq!(d.round(m), d.round_with(m, HalfAwayFromZero), "#{i} m={m}");
         }
     }
 }
@@ -208,6 +565,1908 @@ fn truncate() {
     }
 }
 
+#[test]
+fn range() {
+    use time::DurationRange;
+
+    let max = Duration(i64::MAX);
+    let min = Duration(i64::MIN);
+
+    let test_vector = vec![
+        (
+            Duration(0),
+            SECOND,
+            250 * MILLISECOND,
+            vec![0, 250, 500, 750],
+        ),
+        (
+            Duration(0),
+            SECOND,
+            300 * MILLISECOND,
+            vec![0, 300, 600, 900],
+        ),
+        (
+            SECOND,
+            Duration(0),
+            -400 * MILLISECOND,
+            vec![1000, 600, 200],
+        ),
+        (SECOND, SECOND, MILLISECOND, vec![]),
+        (SECOND, Duration(0), MILLISECOND, vec![]),
+        (Duration(0), SECOND, -MILLISECOND, vec![]),
+        (Duration(0), SECOND, 2 * SECOND, vec![0]),
+    ];
+
+    for (i, (start, end, step, want)) in test_vector.into_iter().enumerate() {
+        let want: Vec<Duration> = want.into_iter().map(|v| v * MILLISECOND).collect();
+        let r = DurationRange::new(start, end, step);
+        assert_eq!((want.len(), Some(want.len())), r.size_hint(), "#{i}");
+        assert_eq!(want, r.collect::<Vec<_>>(), "#{i}");
+    }
+
+    // Stops instead of overflowing.
+    let got: Vec<Duration> = DurationRange::new(max - SECOND, max, 600 * MILLISECOND).collect();
+    assert_eq!(vec![max - SECOND, max - 400 * MILLISECOND], got);
+
+    let got: Vec<Duration> = DurationRange::new(min + NANOSECOND, min, -SECOND).collect();
+    assert_eq!(vec![min + NANOSECOND], got);
+
+    let mut r = DurationRange::new(max - NANOSECOND, max, max);
+    assert_eq!(Some(max - NANOSECOND), r.next());
+    assert_eq!(None, r.next());
+    assert_eq!(None, r.next());
+
+    assert_eq!(
+        Some(u64::MAX as usize),
+        DurationRange::new(min, max, NANOSECOND).size_hint().1
+    );
+}
+
+#[test]
+#[should_panic(expected = "zero step in DurationRange")]
+fn range_zero_step() {
+    time::DurationRange::new(Duration(0), SECOND, Duration(0));
+}
+
+// parsing
+#[test]
+fn parse_duration() {
+    for (i, c) in PARSE_TESTS.iter().enumerate() {
+        let got: Duration = c
+            .input
+            .parse()
+            .expect(&format!("#{} parse '{}'", i, c.input));
+        assert_eq!(c.want, got, "#{} parse '{}'", i, c.input);
+    }
+}
+
+#[cfg(feature = "days")]
+#[test]
+fn parse_duration_extended() {
+    use time::{DAY, WEEK};
+
+    let test_vector = vec![
+        ("1d", DAY),
+        ("2w", 2 * WEEK),
+        ("-1.5d", -(DAY + 12 * HOUR)),
+        ("1w2d3h4m", WEEK + 2 * DAY + 3 * HOUR + 4 * MINUTE),
+        ("106751d23h47m16.854775807s", i64::MAX.into()),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let got =
+            time::parse_duration_extended(input).unwrap_or_else(|_| panic!("#{i} parse '{input}'"));
+        assert_eq!(want, got, "#{i} parse '{input}'");
+    }
+
+    for (i, c) in PARSE_TESTS.iter().enumerate() {
+        let got = time::parse_duration_extended(c.input)
+            .unwrap_or_else(|_| panic!("#{} parse '{}'", i, c.input));
+        assert_eq!(c.want, got, "#{} parse '{}'", i, c.input);
+    }
+
+    assert!(time::parse_duration_extended("15251w").is_err());
+
+    let err = time::parse_duration_extended("1x").unwrap_err();
+    let DurationParseError::UnknownUnit { accepted, .. } = err else {
+        panic!("unexpected error {err:?}");
+    };
+    assert_eq!(UnitSet::Extended, accepted);
+    let want = DurationParser::new()
+        .with_unit("d", DAY)
+        .with_unit("w", WEEK)
+        .accepted_units();
+    assert_eq!(Some(want.as_str()), accepted.units());
+}
+
+#[test]
+fn overflow() {
+    let test_vector = vec![
+        "9223372036854775808ns",
+        "-9223372036854775809ns",
+        "99999999999999999999ns",
+        "2562048h",
+        "2562047h48m",
+        "1h9223372036854775807ns",
+        "0.9223372036854775808s9223372036s",
+        "9223372036854775808ns9223372036854775808ns",
+        "-9223372036854775808ns9223372036854775808ns",
+    ];
+
+    for (i, input) in test_vector.into_iter().enumerate() {
+        match input.parse::<Duration>() {
+            Err(err @ DurationParseError::Overflow { .. }) => {
+                assert_eq!("duration out of range", err.to_string(), "#{i}");
+                assert_eq!(
+                    format!("time: invalid duration \"{input}\""),
+                    err.go_message(input),
+                    "#{i}"
+                );
+            }
+            v => panic!("#{i} unexpected {v:?}"),
+        }
+    }
+
+    let test_vector = vec![
+        Duration::parse_clock("2562048:00:00"),
+        Duration::parse_clock("99999999999999999999:00:00"),
+        Duration::parse_iso8601("PT2562048H"),
+        Duration::parse_iso8601("P15251W"),
+        Duration::parse_iso8601("P99999999999999999999D"),
+    ];
+
+    for (i, v) in test_vector.into_iter().enumerate() {
+        assert!(
+            matches!(v, Err(DurationParseError::Overflow { .. })),
+            "#{i} unexpected {v:?}"
+        );
+    }
+}
+
+#[test]
+fn unknown_unit() {
+    let test_vector = vec![
+        ("1x", "x", 1, false),
+        ("-1h2sec", "sec", 4, false),
+        ("1h 2m", "h ", 1, false),
+        ("3.5fortnights", "fortnights", 3, false),
+        ("1abcdefghijklmnopq", "abcdefghijklmnop", 1, true),
+        ("1ééééééééé", "éééééééé", 1, true),
+    ];
+
+    for (i, (input, want, want_offset, truncated)) in test_vector.into_iter().enumerate() {
+        match input.parse::<Duration>() {
+            Err(DurationParseError::UnknownUnit { unit, offset, .. }) => {
+                assert_eq!(want, unit.as_str(), "#{i}");
+                assert_eq!(want_offset, offset, "#{i}");
+                assert_eq!(truncated, unit.is_truncated(), "#{i}");
+            }
+            v => panic!("#{i} unexpected {v:?}"),
+        }
+    }
+}
+
+#[test]
+fn unknown_unit_accepted() {
+    let test_vector = vec![
+        (
+            DurationParser::new(),
+            "ns, us/µs/μs, ms, s, m, h",
+            UnitSet::Default,
+        ),
+        (
+            DurationParser::new()
+                .with_unit("d", 24 * HOUR)
+                .with_unit("w", 7 * 24 * HOUR)
+                .without_unit("μs"),
+            "ns, us/µs, ms, s, m, h, d, w",
+            UnitSet::Custom,
+        ),
+        (
+            DurationParser::new().with_unit("hour", HOUR),
+            "ns, us/µs/μs, ms, s, m, h/hour",
+            UnitSet::Custom,
+        ),
+        (
+            DurationParser::new()
+                .with_unit("nanoseconds", NANOSECOND)
+                .with_unit("microseconds", MICROSECOND)
+                .with_unit("milliseconds", MILLISECOND)
+                .with_unit("seconds", SECOND),
+            "nanoseconds/ns, microseconds/us/µs/μs, milliseconds/ms, s/seconds, m, h",
+            UnitSet::Custom,
+        ),
+        (
+            DurationParser::empty().with_unit("s", SECOND),
+            "s",
+            UnitSet::Custom,
+        ),
+        (DurationParser::empty(), "", UnitSet::Custom),
+    ];
+
+    for (i, (parser, want, want_set)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, parser.accepted_units(), "#{i}");
+        match parser.parse("1x") {
+            Err(DurationParseError::UnknownUnit { accepted, .. }) => {
+                assert_eq!(want_set, accepted, "#{i}");
+            }
+            v => panic!("#{i} unexpected {v:?}"),
+        }
+    }
+
+    let err = DurationParser::empty()
+        .with_unit("s", SECOND)
+        .parse("1x")
+        .unwrap_err();
+    assert_eq!(r#"unknown unit "x""#, err.to_string());
+}
+
+#[test]
+fn unknown_unit_suggestion() {
+    let test_vector = vec![
+        ("1sec", Some("s")),
+        ("1mins", Some("m")),
+        ("1msec", Some("ms")),
+        ("1hours", Some("h")),
+        ("1S", Some("s")),
+        ("1MS", Some("ms")),
+        ("1mss", Some("ms")),
+        ("1nx", Some("ns")),
+        ("1x", None),
+        ("1days", None),
+        ("1abcdefghijklmnopq", None),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        match input.parse::<Duration>() {
+            Err(DurationParseError::UnknownUnit { suggestion, .. }) => {
+                assert_eq!(want, suggestion.as_ref().map(|v| v.as_str()), "#{i}")
+            }
+            v => panic!("#{i} unexpected {v:?}"),
+        }
+    }
+
+    let err = "1sec".parse::<Duration>().unwrap_err();
+    assert_eq!(
+        r#"unknown unit "sec"; did you mean "s"? (valid units: ns, us/µs/μs, ms, s, m, h)"#,
+        err.to_string()
+    );
+    assert_eq!(
+        r#"time: unknown unit "sec" in duration "1sec""#,
+        err.go_message("1sec")
+    );
+
+    let err = "1µx".parse::<Duration>().unwrap_err();
+    assert_eq!(
+        r#"unknown unit "\u{b5}x"; did you mean "µs"? (valid units: ns, us/µs/μs, ms, s, m, h)"#,
+        err.to_string()
+    );
+
+    let parser = DurationParser::new().with_unit("d", 24 * HOUR);
+    match parser.parse("1days") {
+        Err(DurationParseError::UnknownUnit { suggestion, .. }) => {
+            assert_eq!(Some("d"), suggestion.as_ref().map(|v| v.as_str()))
+        }
+        v => panic!("unexpected {v:?}"),
+    }
+}
+
+#[test]
+fn parse_bytes() {
+    for (i, c) in PARSE_TESTS.iter().enumerate() {
+        let got = Duration::parse_bytes(c.input.as_bytes())
+            .unwrap_or_else(|_| panic!("#{} parse '{}'", i, c.input));
+        assert_eq!(c.want, got, "#{} parse '{}'", i, c.input);
+    }
+
+    let test_vector: Vec<(&[u8], &str)> = vec![
+        (b"1\xffs", "\u{fffd}s"),
+        (b"1\xb5s", "\u{fffd}s"),
+        (b"1x", "x"),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        match Duration::parse_bytes(input) {
+            Err(DurationParseError::UnknownUnit { unit, .. }) => {
+                assert_eq!(want, unit.as_str(), "#{i}")
+            }
+            v => panic!("#{i} unexpected {v:?}"),
+        }
+    }
+
+    assert_eq!(
+        Err(DurationParseError::Invalid { offset: 0 }),
+        Duration::parse_bytes(b"\xff1s")
+    );
+}
+
+#[test]
+fn parse_clock() {
+    let test_vector = vec![
+        ("00:00", Duration(0)),
+        ("0:00:00", Duration(0)),
+        (
+            "01:30:05.250",
+            HOUR + 30 * MINUTE + 5 * SECOND + 250 * MILLISECOND,
+        ),
+        ("1:30:05", HOUR + 30 * MINUTE + 5 * SECOND),
+        ("30:05", 30 * MINUTE + 5 * SECOND),
+        ("5:07", 5 * MINUTE + 7 * SECOND),
+        ("00:00:05,250", 5 * SECOND + 250 * MILLISECOND),
+        ("100:00:00", 100 * HOUR),
+        ("-00:00:01", -SECOND),
+        ("+00:00:01", SECOND),
+        ("00:00:00.0000000019", NANOSECOND),
+        ("2562047:47:16.854775807", i64::MAX.into()),
+        ("-2562047:47:16.854775808", i64::MIN.into()),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let got = Duration::parse_clock(input).unwrap_or_else(|_| panic!("#{i} parse '{input}'"));
+        assert_eq!(want, got, "#{i} parse '{input}'");
+    }
+
+    let bad = vec![
+        "",
+        "5",
+        "1h",
+        ":30",
+        "1:",
+        "1::30",
+        "1:2:3:4",
+        "1:60:00",
+        "1:00:60",
+        "100:00",
+        "1:000:00",
+        "00:00.",
+        "00:00.5s",
+        "00:00 ",
+        "2562047:47:16.854775808",
+    ];
+
+    for (i, input) in bad.into_iter().enumerate() {
+        assert!(
+            Duration::parse_clock(input).is_err(),
+            "#{i} parse '{input}'"
+        );
+    }
+}
+
+#[test]
+fn parse_iso8601() {
+    let test_vector = vec![
+        ("PT0S", Duration(0)),
+        ("P0D", Duration(0)),
+        ("PT1H30M", HOUR + 30 * MINUTE),
+        ("pt1h30m", HOUR + 30 * MINUTE),
+        ("PT36H", 36 * HOUR),
+        ("P1DT12H", 36 * HOUR),
+        ("P2W", 14 * 24 * HOUR),
+        ("P1W1D", 8 * 24 * HOUR),
+        ("PT0.5S", 500 * MILLISECOND),
+        ("PT0,5S", 500 * MILLISECOND),
+        ("PT1M0.000000001S", MINUTE + NANOSECOND),
+        ("PT1.0000000019S", SECOND + NANOSECOND),
+        ("+PT10S", 10 * SECOND),
+        ("-PT10S", -10 * SECOND),
+        ("-P1DT0.5S", -(24 * HOUR + 500 * MILLISECOND)),
+        ("PT2562047H47M16.854775807S", i64::MAX.into()),
+        ("-PT2562047H47M16.854775808S", i64::MIN.into()),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let got = Duration::parse_iso8601(input).unwrap_or_else(|_| panic!("#{i} parse '{input}'"));
+        assert_eq!(want, got, "#{i} parse '{input}'");
+    }
+
+    let bad = vec![
+        "",
+        "P",
+        "PT",
+        "1H",
+        "T1H",
+        "P1Y",
+        "P1M",
+        "P1H",
+        "PT1D",
+        "PT1S1M",
+        "P1D1W",
+        "PT1H1H",
+        "PT1.5H",
+        "PT.5S",
+        "PT1.S",
+        "PT1",
+        "PT1S ",
+        "--PT1S",
+        "PT2562047H47M16.854775808S",
+        "-PT2562047H47M16.854775809S",
+        "PT9223372036854775807S",
+        "P99999999999999999999D",
+    ];
+
+    for (i, input) in bad.into_iter().enumerate() {
+        assert!(
+            Duration::parse_iso8601(input).is_err(),
+            "#{i} parse '{input}'"
+        );
+    }
+}
+
+#[test]
+fn duration_const_parse() {
+    const TIMEOUT: Duration = Duration::const_parse("30s");
+    assert_eq!(30 * SECOND, TIMEOUT);
+
+    assert_eq!(
+        -(HOUR + 500 * MILLISECOND),
+        Duration::const_parse("-1h0.5s")
+    );
+}
+
+#[test]
+#[should_panic(expected = "unknown unit in duration")]
+fn duration_const_parse_panics() {
+    Duration::const_parse("30sec");
+}
+
+#[test]
+fn duration_parser() {
+    let parser = DurationParser::new()
+        .with_unit("mo", 30 * 24 * HOUR)
+        .with_unit("sec", SECOND)
+        .without_unit("m")
+        .without_unit("unknown");
+
+    let test_vector = vec![
+        ("1mo", 30 * 24 * HOUR),
+        ("1.5sec", 1500 * MILLISECOND),
+        ("-1mo2h3sec", -(30 * 24 * HOUR + 2 * HOUR + 3 * SECOND)),
+        ("1h2s", HOUR + 2 * SECOND),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let got = parser
+            .parse(input)
+            .unwrap_or_else(|_| panic!("#{i} parse '{input}'"));
+        assert_eq!(want, got, "#{i} parse '{input}'");
+    }
+
+    for (i, input) in ["1m", "2mon", "1d"].into_iter().enumerate() {
+        assert!(
+            matches!(
+                parser.parse(input),
+                Err(DurationParseError::UnknownUnit { .. })
+            ),
+            "#{i} parse '{input}'"
+        );
+    }
+
+    assert!(DurationParser::empty().parse("1s").is_err());
+    assert_eq!(
+        Ok(Duration(0)),
+        DurationParser::empty().parse("0").map_err(|_| ())
+    );
+
+    for (i, c) in PARSE_TESTS.iter().enumerate() {
+        let got = DurationParser::default()
+            .parse(c.input)
+            .unwrap_or_else(|_| panic!("#{} parse '{}'", i, c.input));
+        assert_eq!(c.want, got, "#{} parse '{}'", i, c.input);
+    }
+}
+
+#[test]
+fn duration_parser_builtin_units() {
+    // Customizing a built-in parser copies its units, which must accept every
+    // listed unit the same way.
+    for (i, parser) in [DurationParser::new(), DurationParser::humantime()]
+        .into_iter()
+        .enumerate()
+    {
+        let custom = parser.clone().with_unit("x", SECOND);
+
+        let err = parser.parse("1?").unwrap_err();
+        let DurationParseError::UnknownUnit { accepted, .. } = err else {
+            panic!("#{i} unexpected error {err:?}");
+        };
+        assert_eq!(
+            Some(parser.accepted_units().as_str()),
+            accepted.units(),
+            "#{i}"
+        );
+        for suffix in parser.accepted_units().split([',', '/']).map(str::trim) {
+            let input = format!("1{suffix}");
+            let want = custom.parse(&input);
+            assert!(want.is_ok(), "#{i} parse '{input}'");
+            assert_eq!(want, parser.parse(&input), "#{i} parse '{input}'");
+        }
+
+        assert_eq!(Ok(SECOND), custom.parse("1x"), "#{i}");
+        assert!(parser.parse("1x").is_err(), "#{i}");
+    }
+
+    let parser = DurationParser::new().without_unit("m");
+    assert!(parser.parse("1m").is_err());
+    assert_eq!(Ok(HOUR), parser.parse("1h"));
+}
+
+#[test]
+#[should_panic(expected = "invalid unit suffix")]
+fn duration_parser_invalid_suffix() {
+    let _ = DurationParser::new().with_unit("1x", SECOND);
+}
+
+#[test]
+fn duration_parser_whitespace() {
+    let parser = DurationParser::new().allow_whitespace(true);
+
+    let test_vector = vec![
+        ("1h 30m", HOUR + 30 * MINUTE),
+        ("2 h", 2 * HOUR),
+        (" 1h\t30m\n", HOUR + 30 * MINUTE),
+        ("- 1.5 s", -1500 * MILLISECOND),
+        ("+ 0", 0.into()),
+        (" 0 ", 0.into()),
+        ("1h30m", HOUR + 30 * MINUTE),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let got = parser
+            .parse(input)
+            .unwrap_or_else(|_| panic!("#{i} parse '{input}'"));
+        assert_eq!(want, got, "#{i} parse '{input}'");
+    }
+
+    for (i, input) in ["1 5h", "1. 5h", "1m s", "", "  ", "1h -30m"]
+        .into_iter()
+        .enumerate()
+    {
+        assert!(parser.parse(input).is_err(), "#{i} parse '{input}'");
+    }
+
+    // Whitespace is rejected by default.
+    for (i, input) in ["1h 30m", "2 h", " 1h"].into_iter().enumerate() {
+        assert!(
+            DurationParser::new().parse(input).is_err(),
+            "#{i} parse '{input}'"
+        );
+    }
+}
+
+// parse errors
+#[test]
+fn parse_error_eq() {
+    let test_vector = vec![
+        ("", DurationParseError::Invalid { offset: 0 }),
+        ("3", DurationParseError::MissUnit { offset: 1 }),
+        ("2562048h", DurationParseError::Overflow { offset: 0 }),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let err = input.parse::<Duration>().unwrap_err();
+        assert_eq!(want, err, "#{i} parse '{input}'");
+        assert_eq!(err.clone(), err, "#{i} parse '{input}'");
+    }
+
+    assert_ne!(
+        "1x".parse::<Duration>().unwrap_err(),
+        "1y".parse::<Duration>().unwrap_err()
+    );
+}
+
+#[test]
+fn parse_error_offset() {
+    let test_vector = vec![
+        ("", 0),
+        ("-", 1),
+        ("1h x", 1),
+        ("-.s", 1),
+        ("1h.m", 2),
+        ("3", 1),
+        ("1h3", 3),
+        ("-1h2sec", 4),
+        ("1é", 1),
+        ("9223372036854775808ns", 0),
+        ("1h9223372036854775807ns", 2),
+        ("2562047h48m", 8),
+        ("2562047h47m16.854775808s", 0),
+        ("9223372036854775808ns9223372036854775808ns", 21),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let err = input.parse::<Duration>().unwrap_err();
+        assert_eq!(want, err.offset(), "#{i} parse '{input}'");
+    }
+
+    let parser = DurationParser::new().allow_whitespace(true);
+    let test_vector = vec![(" 1x ", 2), (" 1 ", 2), ("1h  ?", 4)];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let err = parser.parse(input).unwrap_err();
+        assert_eq!(want, err.offset(), "#{i} parse '{input}'");
+    }
+
+    let test_vector = vec![
+        ("1", 1),
+        ("1:2:3:4", 6),
+        ("1:60", 2),
+        ("1:30:123", 5),
+        ("1:30.", 5),
+        ("1:30x", 4),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let err = Duration::parse_clock(input).unwrap_err();
+        assert_eq!(want, err.offset(), "#{i} parse '{input}'");
+    }
+
+    let test_vector = vec![
+        ("1H", 0),
+        ("-T1H", 1),
+        ("PT", 2),
+        ("P1DT", 4),
+        ("P1H", 2),
+        ("PT1.5H", 5),
+        ("PT1M1H", 5),
+        ("PT1.S", 4),
+        ("P1DX", 3),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let err = Duration::parse_iso8601(input).unwrap_err();
+        assert_eq!(want, err.offset(), "#{i} parse '{input}'");
+    }
+}
+
+#[test]
+fn go_message() {
+    let test_vector = vec![
+        ("", r#"time: invalid duration """#),
+        ("3", r#"time: missing unit in duration "3""#),
+        ("-.s", r#"time: invalid duration "-.s""#),
+        ("1x", r#"time: unknown unit "x" in duration "1x""#),
+        ("3hY", r#"time: unknown unit "hY" in duration "3hY""#),
+        ("1\"\\", r#"time: unknown unit "\"\\" in duration "1\"\\""#),
+        (
+            "1é",
+            r#"time: unknown unit "\xc3\xa9" in duration "1\xc3\xa9""#,
+        ),
+        (
+            "9223372036854775808ns",
+            r#"time: invalid duration "9223372036854775808ns""#,
+        ),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let err = input.parse::<Duration>().unwrap_err();
+        assert_eq!(want, err.go_message(input), "#{i} parse '{input}'");
+    }
+
+    // Only the Go message escapes bytes.
+    let err = "1é".parse::<Duration>().unwrap_err();
+    assert!(err.to_string().starts_with(r#"unknown unit "\u{e9}""#));
+}
+
+#[test]
+fn parse_error_diagnostic() {
+    let test_vector = vec![
+        (
+            "1h30x",
+            "1h30x\n    ^ unknown unit \"x\" (valid units: ns, us/µs/μs, ms, s, m, h)",
+        ),
+        ("", "\n^ invalid duration"),
+        ("5", "5\n ^ missing unit in duration"),
+        (
+            "1µs2x",
+            "1µs2x\n    ^ unknown unit \"x\" (valid units: ns, us/µs/μs, ms, s, m, h)",
+        ),
+        ("é", "é\n^ invalid duration"),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let err = input.parse::<Duration>().unwrap_err();
+        assert_eq!(want, err.diagnostic(input), "#{i} parse '{input}'");
+    }
+}
+
+#[cfg(feature = "miette")]
+#[test]
+fn miette_diagnostic() {
+    use miette::Diagnostic;
+
+    let test_vector = vec![
+        ("1h30x", "time::unknown_unit", 4, 1),
+        ("1sec", "time::unknown_unit", 1, 3),
+        ("1h3", "time::missing_unit", 3, 0),
+        ("1h.", "time::invalid_duration", 2, 0),
+        ("1abcdefghijklmnopqrst", "time::unknown_unit", 1, 20),
+    ];
+
+    for (i, (input, code, offset, len)) in test_vector.into_iter().enumerate() {
+        let err = input.parse::<Duration>().unwrap_err();
+        assert_eq!(code, err.code().unwrap().to_string(), "#{i}");
+
+        let labels: Vec<_> = err.labels().unwrap().collect();
+        assert_eq!(1, labels.len(), "#{i}");
+        assert_eq!(offset, labels[0].offset(), "#{i}");
+        assert_eq!(len, labels[0].len(), "#{i}");
+    }
+
+    // The span covers the invalid UTF-8 of the input, not its replacement.
+    let err = Duration::parse_bytes(b"1\xffs").unwrap_err();
+    let labels: Vec<_> = err.labels().unwrap().collect();
+    assert_eq!((1, 2), (labels[0].offset(), labels[0].len()));
+}
+
+// formatting
+#[test]
+fn to_string() {
+    let test_vector: Vec<(&str, Duration)> = vec![
+        ("0s", 0.into()),
+        ("1ns", 1 * NANOSECOND),
+        ("1.1µs", 1100 * NANOSECOND),
+        ("2.2ms", 2200 * MICROSECOND),
+        ("3.3s", 3300 * MILLISECOND),
+        ("4m5s", 4 * MINUTE + 5 * SECOND),
+        ("4m5.001s", 4 * MINUTE + 5001 * MILLISECOND),
+        ("5h6m7.001s", 5 * HOUR + 6 * MINUTE + 7001 * MILLISECOND),
+        ("8m0.000000001s", 8 * MINUTE + 1 * NANOSECOND),
+        ("2562047h47m16.854775807s", i64::MAX.into()),
+        ("-2562047h47m16.854775808s", i64::MIN.into()),
+    ]
+    .into_iter()
+    .map(|(s, d)| (s, d.into()))
+    .collect();
+
+    for (i, (expect, d)) in test_vector.into_iter().enumerate() {
+        assert_eq!(
+            expect,
+            d.to_string(),
+            "#{} Duration({}).to_string()",
+            i,
+            d.0
+        );
+
+        if d.0 > 0 {
+            let expect = format!("-{expect}");
+            let d = Duration(-d.0);
+            assert_eq!(
+                expect,
+                d.to_string(),
+                "#{} Duration({}).to_string()",
+                i,
+                -d.0
+            );
+        }
+    }
+}
+
+#[test]
+fn to_string_with_precision() {
+    let d = HOUR + 15 * MINUTE + 30 * SECOND + 918273645 * NANOSECOND;
+
+    let test_vector: Vec<(String, &str)> = vec![
+        (format!("{d:.0}"), "1h15m31s"),
+        (format!("{d:.1}"), "1h15m30.9s"),
+        (format!("{d:.3}"), "1h15m30.918s"),
+        (format!("{d:.6}"), "1h15m30.918274s"),
+        (format!("{d:.9}"), "1h15m30.918273645s"),
+        (format!("{d:.12}"), "1h15m30.918273645s"),
+        (format!("{:.1}", 30 * SECOND + 950 * MILLISECOND), "31s"),
+        (format!("{:.0}", 59 * SECOND + 500 * MILLISECOND), "1m0s"),
+        (format!("{:.2}", 1234567 * NANOSECOND), "1.23ms"),
+        (format!("{:.2}", 999999 * NANOSECOND), "1ms"),
+        (format!("{:.0}", 1500 * NANOSECOND), "2µs"),
+        (format!("{:.0}", 7 * NANOSECOND), "7ns"),
+        (format!("{:.0}", Duration(0)), "0s"),
+        (format!("{:.3}", -d), "-1h15m30.918s"),
+        (format!("{:.0}", Duration(i64::MIN)), "-2562047h47m17s"),
+        (format!("{:>14.1}", d), "    1h15m30.9s"),
+        (format!("{:<14.1}|", d), "1h15m30.9s    |"),
+        (format!("{:*^14.1}", d), "**1h15m30.9s**"),
+        (format!("{:2.1}", d), "1h15m30.9s"),
+        (format!("{:>8}", 300 * MILLISECOND), "   300ms"),
+    ];
+
+    for (i, (got, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, got, "#{i}");
+    }
+}
+
+#[test]
+fn format_into() {
+    let test_vector = vec![
+        (Duration(0), "0s"),
+        (1500 * MICROSECOND, "1.5ms"),
+        (
+            HOUR + 15 * MINUTE + 30 * SECOND + 918273645 * NANOSECOND,
+            "1h15m30.918273645s",
+        ),
+        (Duration(i64::MIN), "-2562047h47m16.854775808s"),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        let mut got = String::from(">");
+        d.format_into(&mut got).unwrap();
+        assert_eq!(format!(">{want}"), got, "#{i}");
+
+        #[cfg(feature = "std")]
+        {
+            let mut got = Vec::new();
+            d.write_to(&mut got).unwrap();
+            assert_eq!(want.as_bytes(), got, "#{i}");
+        }
+    }
+}
+
+#[test]
+fn format_with() {
+    use time::{DurationUnit as U, FormatOptions};
+
+    let d = HOUR + 15 * MINUTE + 30 * SECOND + 918273645 * NANOSECOND;
+
+    let test_vector = vec![
+        (d, U::Hour, U::Nanosecond, "1h15m30.918273645s"),
+        (d, U::Minute, U::Nanosecond, "75m30.918273645s"),
+        (d, U::Second, U::Nanosecond, "4530.918273645s"),
+        (d, U::Millisecond, U::Nanosecond, "4530918.273645ms"),
+        (d, U::Nanosecond, U::Nanosecond, "4530918273645ns"),
+        (d, U::Hour, U::Microsecond, "1h15m30.918274s"),
+        (d, U::Hour, U::Second, "1h15m31s"),
+        (d, U::Hour, U::Minute, "1h16m"),
+        (d, U::Hour, U::Hour, "1h"),
+        (d, U::Minute, U::Minute, "76m"),
+        (d, U::Second, U::Hour, "1h"),
+        (-d, U::Minute, U::Second, "-75m31s"),
+        (2 * HOUR, U::Hour, U::Nanosecond, "2h0m0s"),
+        (2 * HOUR, U::Hour, U::Minute, "2h0m"),
+        (2 * HOUR, U::Minute, U::Nanosecond, "120m0s"),
+        (59 * MINUTE + 31 * SECOND, U::Hour, U::Minute, "1h0m"),
+        (90 * SECOND, U::Hour, U::Second, "1m30s"),
+        (1500 * MICROSECOND, U::Hour, U::Nanosecond, "1.5ms"),
+        (1500 * MICROSECOND, U::Microsecond, U::Nanosecond, "1500µs"),
+        (1500 * MICROSECOND, U::Hour, U::Millisecond, "2ms"),
+        (1500 * MICROSECOND, U::Hour, U::Second, "0s"),
+        (400 * MILLISECOND, U::Hour, U::Hour, "0h"),
+        (-400 * MILLISECOND, U::Hour, U::Minute, "0m"),
+        (Duration(0), U::Hour, U::Nanosecond, "0s"),
+        (i64::MIN.into(), U::Hour, U::Second, "-2562047h47m17s"),
+    ];
+
+    for (i, (d, largest, smallest, want)) in test_vector.into_iter().enumerate() {
+        let opts = FormatOptions::new()
+            .with_largest_unit(largest)
+            .with_smallest_unit(smallest);
+        assert_eq!(want, d.format_with(&opts), "#{i}");
+    }
+
+    let test_vector: Vec<Duration> = vec![
+        0.into(),
+        NANOSECOND,
+        1100 * NANOSECOND,
+        2200 * MICROSECOND,
+        3300 * MILLISECOND,
+        4 * MINUTE + 5 * SECOND,
+        5 * HOUR + 6 * MINUTE + 7001 * MILLISECOND,
+        8 * MINUTE + NANOSECOND,
+        i64::MAX.into(),
+        i64::MIN.into(),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        assert_eq!(
+            d.to_string(),
+            d.format_with(&FormatOptions::default()),
+            "#{i}"
+        );
+    }
+}
+
+#[test]
+fn format_with_separator() {
+    use time::{DurationUnit as U, FormatOptions};
+
+    let d = HOUR + 2 * MINUTE + 3 * SECOND + 400 * MILLISECOND;
+
+    let test_vector = vec![
+        (d, U::Hour, U::Nanosecond, " ", "1h 2m 3.4s"),
+        (d, U::Hour, U::Second, ", ", "1h, 2m, 3s"),
+        (-d, U::Minute, U::Second, " ", "-62m 3s"),
+        (2 * HOUR, U::Hour, U::Minute, " ", "2h 0m"),
+        (3 * SECOND, U::Hour, U::Nanosecond, " ", "3s"),
+        (400 * MILLISECOND, U::Hour, U::Nanosecond, " ", "400ms"),
+        (Duration(0), U::Hour, U::Nanosecond, " ", "0s"),
+    ];
+
+    for (i, (d, largest, smallest, separator, want)) in test_vector.into_iter().enumerate() {
+        let opts = FormatOptions::new()
+            .with_largest_unit(largest)
+            .with_smallest_unit(smallest)
+            .with_separator(separator);
+        assert_eq!(want, d.format_with(&opts), "#{i}");
+    }
+}
+
+#[test]
+fn to_iso8601() {
+    let test_vector: Vec<(&str, Duration)> = vec![
+        ("PT0S", 0.into()),
+        ("PT0.000000001S", NANOSECOND),
+        ("PT0.0000011S", 1100 * NANOSECOND),
+        ("PT3.3S", 3300 * MILLISECOND),
+        ("PT4M5S", 4 * MINUTE + 5 * SECOND),
+        ("PT4M", 4 * MINUTE),
+        ("PT5H7.001S", 5 * HOUR + 7001 * MILLISECOND),
+        ("PT48H", 48 * HOUR),
+        ("PT2562047H47M16.854775807S", i64::MAX.into()),
+        ("-PT2562047H47M16.854775808S", i64::MIN.into()),
+    ];
+
+    for (i, (expect, d)) in test_vector.into_iter().enumerate() {
+        assert_eq!(expect, d.to_iso8601(), "#{i}");
+        assert_eq!(d, Duration::parse_iso8601(expect).unwrap(), "#{i}");
+
+        if d.0 > 0 {
+            let expect = format!("-{expect}");
+            assert_eq!(expect, (-d).to_iso8601(), "#{i}");
+        }
+    }
+}
+
+#[test]
+fn humantime() {
+    let test_vector = vec![
+        (Duration(0), "0s"),
+        (NANOSECOND, "1ns"),
+        (1500 * MILLISECOND, "1s 500ms"),
+        (90 * MINUTE, "1h 30m"),
+        (24 * HOUR, "1day"),
+        (49 * HOUR, "2days 1h"),
+        (-90 * SECOND, "-1m 30s"),
+        (
+            Duration(i64::MAX),
+            "292years 3months 7days 16h 6m 28s 854ms 775us 807ns",
+        ),
+        (
+            Duration(i64::MIN),
+            "-292years 3months 7days 16h 6m 28s 854ms 775us 808ns",
+        ),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.to_humantime(), "#{i}");
+        assert_eq!(Ok(d), Duration::parse_humantime(want), "#{i}");
+    }
+
+    let test_vector = vec![
+        ("2days 3hours", Ok(51 * HOUR)),
+        ("1h 30min", Ok(90 * MINUTE)),
+        ("15days 2min 2s", Ok(360 * HOUR + 122 * SECOND)),
+        ("1M", Ok(2_630_016 * SECOND)),
+        ("1m", Ok(MINUTE)),
+        ("1y 1w", Ok(31_557_600 * SECOND + 168 * HOUR)),
+        ("10msec 5usec 3nsec", Ok(Duration(10_005_003))),
+        (" 1hr  2mins ", Ok(62 * MINUTE)),
+        ("", Err(DurationParseError::Invalid { offset: 0 })),
+        ("1", Err(DurationParseError::MissUnit { offset: 1 })),
+        ("300years", Err(DurationParseError::Overflow { offset: 0 })),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, Duration::parse_humantime(input), "#{i}");
+    }
+
+    assert!(matches!(
+        Duration::parse_humantime("1fortnight"),
+        Err(DurationParseError::UnknownUnit { offset: 1, .. })
+    ));
+}
+
+#[cfg(feature = "humanize")]
+#[test]
+fn humanize() {
+    let test_vector = vec![
+        (Duration(0), "0 seconds", "now"),
+        (NANOSECOND, "less than a second", "in less than a second"),
+        (
+            999 * MILLISECOND,
+            "less than a second",
+            "in less than a second",
+        ),
+        (SECOND, "1 second", "in 1 second"),
+        (1500 * MILLISECOND, "about 2 seconds", "in about 2 seconds"),
+        (
+            59 * SECOND + 500 * MILLISECOND,
+            "about 1 minute",
+            "in about 1 minute",
+        ),
+        (5 * MINUTE, "5 minutes", "in 5 minutes"),
+        (
+            5 * MINUTE + 29 * SECOND,
+            "about 5 minutes",
+            "in about 5 minutes",
+        ),
+        (59 * MINUTE + 30 * SECOND, "about 1 hour", "in about 1 hour"),
+        (2 * HOUR, "2 hours", "in 2 hours"),
+        (23 * HOUR + 45 * MINUTE, "about 1 day", "in about 1 day"),
+        (24 * HOUR, "1 day", "in 1 day"),
+        (75 * HOUR, "about 3 days", "in about 3 days"),
+        (i64::MAX.into(), "about 106752 days", "in about 106752 days"),
+    ];
+
+    for (i, (d, magnitude, relative)) in test_vector.into_iter().enumerate() {
+        assert_eq!(magnitude, d.humanize(), "#{i}");
+        assert_eq!(relative, d.humanize_relative(), "#{i}");
+
+        if d.0 > 0 {
+            assert_eq!(magnitude, (-d).humanize(), "#{i}");
+            assert_eq!(format!("{magnitude} ago"), (-d).humanize_relative(), "#{i}");
+        }
+    }
+
+    assert_eq!("about 106752 days", Duration(i64::MIN).humanize());
+}
+
+#[cfg(feature = "humanize")]
+#[test]
+fn humanize_in() {
+    use time::Locale;
+
+    let test_vector = vec![
+        (Locale::Chinese, Duration(0), "0秒", "现在"),
+        (Locale::Chinese, 500 * MILLISECOND, "不到1秒", "不到1秒后"),
+        (Locale::Chinese, 75 * HOUR, "大约3天", "大约3天后"),
+        (Locale::French, Duration(0), "0 seconde", "maintenant"),
+        (Locale::French, SECOND, "1 seconde", "dans 1 seconde"),
+        (Locale::French, -2 * MINUTE, "2 minutes", "il y a 2 minutes"),
+        (
+            Locale::French,
+            75 * HOUR,
+            "environ 3 jours",
+            "dans environ 3 jours",
+        ),
+        (
+            Locale::German,
+            500 * MILLISECOND,
+            "weniger als eine Sekunde",
+            "in weniger als einer Sekunde",
+        ),
+        (Locale::German, 24 * HOUR, "1 Tag", "in 1 Tag"),
+        (
+            Locale::German,
+            -75 * HOUR,
+            "etwa 3 Tage",
+            "vor etwa 3 Tagen",
+        ),
+        (Locale::Russian, MINUTE, "1 минута", "через 1 минуту"),
+        (Locale::Russian, -3 * MINUTE, "3 минуты", "3 минуты назад"),
+        (Locale::Russian, 11 * SECOND, "11 секунд", "через 11 секунд"),
+        (Locale::Russian, 21 * HOUR, "21 час", "через 21 час"),
+        (Locale::Russian, 22 * HOUR, "22 часа", "через 22 часа"),
+        (Locale::Russian, 14 * 24 * HOUR, "14 дней", "через 14 дней"),
+        (
+            Locale::Russian,
+            -(101 * 24 * HOUR + HOUR),
+            "примерно 101 день",
+            "примерно 101 день назад",
+        ),
+        (Locale::Spanish, HOUR, "1 hora", "dentro de 1 hora"),
+        (Locale::Spanish, -5 * 24 * HOUR, "5 días", "hace 5 días"),
+        (
+            Locale::Spanish,
+            90 * SECOND,
+            "alrededor de 2 minutos",
+            "dentro de alrededor de 2 minutos",
+        ),
+    ];
+
+    for (i, (locale, d, magnitude, relative)) in test_vector.into_iter().enumerate() {
+        assert_eq!(magnitude, d.humanize_in(locale), "#{i}");
+        assert_eq!(relative, d.humanize_relative_in(locale), "#{i}");
+    }
+
+    let d = 75 * HOUR;
+    assert_eq!(d.humanize(), d.humanize_in(Locale::English));
+    assert_eq!(
+        d.humanize_relative(),
+        d.humanize_relative_in(Locale::English)
+    );
+}
+
+// conversions
+#[test]
+fn from_std() {
+    let test_vector = vec![
+        (std::time::Duration::ZERO, Duration(0)),
+        (std::time::Duration::from_nanos(1), NANOSECOND),
+        (
+            std::time::Duration::new(90, 5),
+            90 * SECOND + 5 * NANOSECOND,
+        ),
+        (
+            std::time::Duration::from_nanos(i64::MAX as u64),
+            i64::MAX.into(),
+        ),
+        (std::time::Duration::from_nanos(u64::MAX), i64::MAX.into()),
+        (std::time::Duration::MAX, i64::MAX.into()),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, Duration::from(d), "#{i}");
+    }
+}
+
+#[test]
+fn try_into_std() {
+    let test_vector = vec![
+        (Duration(0), Some(std::time::Duration::ZERO)),
+        (NANOSECOND, Some(std::time::Duration::from_nanos(1))),
+        (
+            90 * SECOND + 5 * NANOSECOND,
+            Some(std::time::Duration::new(90, 5)),
+        ),
+        (
+            i64::MAX.into(),
+            Some(std::time::Duration::from_nanos(i64::MAX as u64)),
+        ),
+        (-NANOSECOND, None),
+        (i64::MIN.into(), None),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        let got: Option<std::time::Duration> = d.try_into().ok();
+        assert_eq!(want, got, "#{i}");
+        assert_eq!(want, d.to_std().ok(), "#{i}");
+    }
+}
+
+#[test]
+fn to_std_abs() {
+    let test_vector = vec![
+        (Duration(0), std::time::Duration::ZERO),
+        (-NANOSECOND, std::time::Duration::from_nanos(1)),
+        (90 * SECOND, std::time::Duration::from_secs(90)),
+        (-90 * SECOND, std::time::Duration::from_secs(90)),
+        (
+            i64::MIN.into(),
+            std::time::Duration::from_nanos(i64::MIN.unsigned_abs()),
+        ),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.to_std_abs(), "#{i}");
+    }
+}
+
+#[test]
+fn to_bytes() {
+    use time::BinaryDecodeError;
+
+    let test_vector = vec![
+        (Duration(i64::MIN), [1, 0, 0, 0, 0, 0, 0, 0, 0]),
+        (
+            -NANOSECOND,
+            [1, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+        ),
+        (Duration(0), [1, 0x80, 0, 0, 0, 0, 0, 0, 0]),
+        (SECOND, [1, 0x80, 0, 0, 0, 0x3b, 0x9a, 0xca, 0]),
+        (
+            Duration(i64::MAX),
+            [1, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+        ),
+    ];
+
+    let mut last = None;
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.to_bytes(), "#{i}");
+        assert_eq!(Ok(d), Duration::from_bytes(&want), "#{i}");
+        assert!(last < Some(want), "#{i}");
+        last = Some(want);
+    }
+
+    let test_vector: Vec<(&[u8], BinaryDecodeError)> = vec![
+        (&[], BinaryDecodeError::InvalidLength { len: 0 }),
+        (
+            &[1, 0x80, 0, 0, 0, 0, 0, 0],
+            BinaryDecodeError::InvalidLength { len: 8 },
+        ),
+        (&[1; 10], BinaryDecodeError::InvalidLength { len: 10 }),
+        (
+            &[2, 0x80, 0, 0, 0, 0, 0, 0, 0],
+            BinaryDecodeError::UnknownVersion(2),
+        ),
+    ];
+
+    for (i, (b, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(Err(want), Duration::from_bytes(b), "#{i}");
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    use time::DurationOutOfRangeError;
+
+    let test_vector = vec![
+        Duration(0),
+        Duration(1),
+        -HOUR - 30 * MINUTE,
+        Duration(i64::MAX),
+        Duration(i64::MIN),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        let c = chrono::Duration::from(d);
+        assert_eq!(Some(d.nanoseconds()), c.num_nanoseconds(), "#{i}");
+        assert_eq!(Ok(d), Duration::try_from(c), "#{i}");
+    }
+
+    let c = chrono::Duration::nanoseconds(i64::MAX) + chrono::Duration::nanoseconds(1);
+    assert_eq!(Err(DurationOutOfRangeError), Duration::try_from(c));
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn jiff() {
+    use jiff::{SignedDuration, Span, ToSpan};
+    use time::DurationOutOfRangeError;
+
+    let test_vector = vec![
+        Duration(0),
+        Duration(1),
+        -HOUR - 1500 * MILLISECOND,
+        Duration(i64::MAX),
+        Duration(i64::MIN),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        let v = SignedDuration::from(d);
+        assert_eq!(d.nanoseconds() as i128, v.as_nanos(), "#{i}");
+        assert_eq!(Ok(d), Duration::try_from(v), "#{i}");
+
+        let span = Span::from(d);
+        assert_eq!(v, SignedDuration::try_from(span).unwrap(), "#{i}");
+        assert_eq!(Ok(d), Duration::try_from(span), "#{i}");
+    }
+
+    let span = Span::from(-HOUR - 1500 * MILLISECOND);
+    assert_eq!(-3601, span.get_seconds());
+    assert_eq!(-500, span.get_milliseconds());
+
+    let test_vector = vec![
+        (2.hours().minutes(30), Ok(150 * MINUTE)),
+        (1.day(), Err(DurationOutOfRangeError)),
+        (1.month(), Err(DurationOutOfRangeError)),
+        (2_562_048.hours(), Err(DurationOutOfRangeError)),
+    ];
+
+    for (i, (span, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, Duration::try_from(span), "#{i}");
+    }
+
+    let v = SignedDuration::from_nanos(i64::MAX) + SignedDuration::from_nanos(1);
+    assert_eq!(Err(DurationOutOfRangeError), Duration::try_from(v));
+}
+
+#[cfg(feature = "time03")]
+#[test]
+fn time03() {
+    use time::DurationOutOfRangeError;
+
+    let test_vector = vec![
+        Duration(0),
+        Duration(1),
+        -HOUR - 30 * MINUTE,
+        Duration(i64::MAX),
+        Duration(i64::MIN),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        let v = time03::Duration::from(d);
+        assert_eq!(d.nanoseconds() as i128, v.whole_nanoseconds(), "#{i}");
+        assert_eq!(Ok(d), Duration::try_from(v), "#{i}");
+    }
+
+    let v = time03::Duration::nanoseconds(i64::MAX) + time03::Duration::nanoseconds(1);
+    assert_eq!(Err(DurationOutOfRangeError), Duration::try_from(v));
+}
+
+// serialization
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let test_vector = vec![
+        (Duration(0), r#""0s""#),
+        (90 * MINUTE, r#""1h30m0s""#),
+        (-1500 * MILLISECOND, r#""-1.5s""#),
+        (Duration(i64::MIN), r#""-2562047h47m16.854775808s""#),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        let got = serde_json::to_string(&d).unwrap();
+        assert_eq!(want, got, "#{i}");
+
+        let got: Duration = serde_json::from_str(&got).unwrap();
+        assert_eq!(d, got, "#{i}");
+    }
+
+    let test_vector = vec![
+        ("1500000000", 1500 * MILLISECOND),
+        ("-1", -NANOSECOND),
+        ("9223372036854775807", Duration(i64::MAX)),
+        (r#""1h30m""#, 90 * MINUTE),
+        (r#"{"secs": 1, "nanos": 500000000}"#, 1500 * MILLISECOND),
+        (r#"{"nanos": -1, "seconds": -2}"#, -2 * SECOND - NANOSECOND),
+        (r#"{"secs": 3}"#, 3 * SECOND),
+        (r#"{"secs": 0, "nanos": -5}"#, -5 * NANOSECOND),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let got: Duration = serde_json::from_str(input).unwrap();
+        assert_eq!(want, got, "#{i}");
+    }
+
+    let bad = vec![
+        r#""1x""#,
+        r#""""#,
+        "9223372036854775808",
+        "1.5",
+        "null",
+        r#"{"secs": 9223372037}"#,
+        r#"{"secs": 1, "secs": 2}"#,
+        r#"{"secs": 1, "millis": 2}"#,
+        r#"{"secs": "1"}"#,
+        "{}",
+        r#"{"nanos": 5}"#,
+        r#"{"secs": 1, "nanos": -1}"#,
+        r#"{"secs": -1, "nanos": 1}"#,
+        r#"{"secs": 1, "nanos": 5000000000}"#,
+        r#"{"secs": 0, "nanos": -1000000000}"#,
+    ];
+
+    for (i, input) in bad.into_iter().enumerate() {
+        assert!(
+            serde_json::from_str::<Duration>(input).is_err(),
+            "#{i} parse '{input}'"
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_binary() {
+    let test_vector = vec![
+        Duration(0),
+        90 * MINUTE,
+        -1500 * MILLISECOND,
+        Duration(i64::MIN),
+        Duration(i64::MAX),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        let encoded = bincode::serialize(&d).unwrap();
+        assert_eq!(d.0.to_le_bytes().as_slice(), encoded, "#{i}");
+
+        let got: Duration = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(d, got, "#{i}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_error() {
+    let test_vector = vec![
+        (
+            "1h.",
+            r#"{"kind":"invalid","message":"invalid duration","offset":2}"#,
+        ),
+        (
+            "1h3",
+            r#"{"kind":"missing_unit","message":"missing unit in duration","offset":3}"#,
+        ),
+        (
+            "2562048h",
+            r#"{"kind":"overflow","message":"duration out of range","offset":0}"#,
+        ),
+        (
+            "1x",
+            r#"{"kind":"unknown_unit","message":"unknown unit \"x\" (valid units: ns, us/µs/μs, ms, s, m, h)","offset":1,"unit":"x","suggestion":null}"#,
+        ),
+        (
+            "1sec",
+            r#"{"kind":"unknown_unit","message":"unknown unit \"sec\"; did you mean \"s\"? (valid units: ns, us/µs/μs, ms, s, m, h)","offset":1,"unit":"sec","suggestion":"s"}"#,
+        ),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        let err = input.parse::<Duration>().unwrap_err();
+        let got = serde_json::to_string(&err).unwrap_or_else(|_| panic!("#{i} serialize"));
+        assert_eq!(want, got, "#{i}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_humantime() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "time::serde::duration_humantime")]
+        timeout: Duration,
+    }
+
+    let c = Config {
+        timeout: 49 * HOUR + 500 * MILLISECOND,
+    };
+    let got = serde_json::to_string(&c).unwrap();
+    assert_eq!(r#"{"timeout":"2days 1h 500ms"}"#, got);
+    assert_eq!(c, serde_json::from_str(&got).unwrap());
+
+    let got: Config = serde_json::from_str(r#"{"timeout":"1hour 30mins"}"#).unwrap();
+    assert_eq!(90 * MINUTE, got.timeout);
+
+    assert!(serde_json::from_str::<Config>(r#"{"timeout":"1 fortnight"}"#).is_err());
+}
+
+#[cfg(feature = "prost")]
+#[test]
+fn prost() {
+    use time::DurationOutOfRangeError;
+
+    let proto = |seconds, nanos| prost_types::Duration { seconds, nanos };
+
+    let test_vector = vec![
+        (Duration(0), proto(0, 0)),
+        (Duration(1), proto(0, 1)),
+        (-HOUR - 1500 * MILLISECOND, proto(-3601, -500_000_000)),
+        (Duration(i64::MAX), proto(9_223_372_036, 854_775_807)),
+        (Duration(i64::MIN), proto(-9_223_372_036, -854_775_808)),
+    ];
+
+    for (i, (d, v)) in test_vector.into_iter().enumerate() {
+        assert_eq!(v, prost_types::Duration::from(d), "#{i}");
+        assert_eq!(Ok(d), Duration::try_from(v), "#{i}");
+    }
+
+    let test_vector = vec![
+        (proto(0, -1), Ok(-NANOSECOND)),
+        (proto(1, -1), Err(DurationOutOfRangeError)),
+        (proto(-1, 1), Err(DurationOutOfRangeError)),
+        (proto(0, 1_000_000_000), Err(DurationOutOfRangeError)),
+        (proto(0, -1_000_000_000), Err(DurationOutOfRangeError)),
+        (
+            proto(9_223_372_036, 854_775_808),
+            Err(DurationOutOfRangeError),
+        ),
+        (proto(315_576_000_000, 0), Err(DurationOutOfRangeError)),
+    ];
+
+    for (i, (v, expect)) in test_vector.into_iter().enumerate() {
+        assert_eq!(expect, Duration::try_from(v), "#{i}");
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn rkyv() {
+    use rkyv::rancor::Error;
+    use time::ArchivedDuration;
+
+    let test_vector = vec![
+        Duration(0),
+        Duration(1),
+        -HOUR - 30 * MINUTE,
+        Duration(i64::MAX),
+        Duration(i64::MIN),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        let bytes = rkyv::to_bytes::<Error>(&d).unwrap();
+        assert_eq!(d.nanoseconds().to_le_bytes(), bytes.as_slice(), "#{i}");
+
+        let archived = rkyv::access::<ArchivedDuration, Error>(&bytes).unwrap();
+        assert_eq!(
+            d,
+            rkyv::deserialize::<Duration, Error>(archived).unwrap(),
+            "#{i}"
+        );
+    }
+}
+
+// integrations
+#[cfg(feature = "clap")]
+#[test]
+fn value_parser() {
+    use clap::{value_parser, Arg, Command};
+    use time::DurationValueParser;
+
+    let cmd = Command::new("app")
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_parser(value_parser!(Duration)),
+        )
+        .arg(
+            Arg::new("interval")
+                .long("interval")
+                .value_parser(DurationValueParser::from(
+                    DurationParser::empty().with_unit("sec", SECOND),
+                )),
+        );
+
+    let test_vector = vec![
+        (vec!["--timeout", "30s"], Some(30 * SECOND), None),
+        (vec!["--timeout=-1h30m"], Some(-90 * MINUTE), None),
+        (vec!["--interval", "1.5sec"], None, Some(1500 * MILLISECOND)),
+    ];
+
+    for (i, (args, timeout, interval)) in test_vector.into_iter().enumerate() {
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["app"].into_iter().chain(args))
+            .unwrap();
+        assert_eq!(timeout.as_ref(), matches.get_one("timeout"), "#{i}");
+        assert_eq!(interval.as_ref(), matches.get_one("interval"), "#{i}");
+    }
+
+    let test_vector = vec![
+        (
+            vec!["--timeout", "30x"],
+            r#"invalid value '30x' for '--timeout <timeout>': unknown unit "x" (valid units: ns, us/µs/μs, ms, s, m, h)"#,
+        ),
+        (
+            vec!["--interval", "1s"],
+            r#"invalid value '1s' for '--interval <interval>': unknown unit "s"; did you mean "sec"? (valid units: sec)"#,
+        ),
+        (
+            vec!["--timeout", ""],
+            "invalid value '' for '--timeout <timeout>': invalid duration",
+        ),
+    ];
+
+    for (i, (args, want)) in test_vector.into_iter().enumerate() {
+        let err = cmd
+            .clone()
+            .try_get_matches_from(["app"].into_iter().chain(args))
+            .unwrap_err();
+        assert_eq!(clap::error::ErrorKind::ValueValidation, err.kind(), "#{i}");
+        assert_eq!(format!("error: {want}\n"), err.to_string(), "#{i}");
+    }
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn json_schema() {
+    let schema = serde_json::to_value(schemars::schema_for!(Duration)).unwrap();
+    assert_eq!("Duration", schema["title"]);
+    assert_eq!("string", schema["type"]);
+
+    let pattern = schema["pattern"].as_str().unwrap();
+    let examples = schema["examples"].as_array().unwrap();
+    assert!(!examples.is_empty());
+
+    // Every example parses, and the pattern is anchored like a duration.
+    for (i, v) in examples.iter().enumerate() {
+        let v = v.as_str().unwrap();
+        assert!(v.parse::<Duration>().is_ok(), "#{i} parse '{v}'");
+    }
+    assert!(pattern.starts_with('^') && pattern.ends_with('$'));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn fuzz_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let test_vector = vec![
+        (vec![0u8; 8], Duration(0)),
+        (vec![0xff; 8], Duration(-1)),
+        (vec![0, 0, 0, 0, 0, 0, 0, 0x80], Duration(i64::MIN)),
+    ];
+
+    for (i, (data, want)) in test_vector.into_iter().enumerate() {
+        let got = Duration::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(want, got, "#{i}");
+    }
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn proptest_parse_to_string(d in proptest::prelude::any::<Duration>()) {
+        proptest::prop_assert_eq!(d, d.to_string().parse::<Duration>().unwrap());
+    }
+
+    #[test]
+    fn proptest_duration_strategy(d in time::duration_strategy(-MINUTE..=MINUTE)) {
+        proptest::prop_assert!(d >= -MINUTE && d <= MINUTE);
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn gen_range() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for _ in 0..1000 {
+        let d = rng.gen_range(SECOND..5 * SECOND);
+        assert!(d >= SECOND && d < 5 * SECOND, "{d}");
+
+        let d = rng.gen_range(-MINUTE..=MINUTE);
+        assert!(d >= -MINUTE && d <= MINUTE, "{d}");
+
+        // The full range must not overflow.
+        rng.gen_range(Duration(i64::MIN)..=Duration(i64::MAX));
+    }
+
+    assert_eq!(SECOND, rng.gen_range(SECOND..=SECOND));
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi() {
+    use std::ffi::CStr;
+    use std::ptr;
+
+    use time::ffi::*;
+
+    let ok = |d: Duration| TimeDurationResult {
+        status: TimeStatus::Ok,
+        offset: 0,
+        nanoseconds: d.nanoseconds(),
+    };
+    let err = |status, offset| TimeDurationResult {
+        status,
+        offset,
+        nanoseconds: 0,
+    };
+
+    let test_vector = vec![
+        ("1h30m\0", ok(90 * MINUTE)),
+        ("-1.5s\0", ok(-1500 * MILLISECOND)),
+        ("\0", err(TimeStatus::InvalidDuration, 0)),
+        ("1h30\0", err(TimeStatus::MissingUnit, 4)),
+        ("9223372037s\0", err(TimeStatus::Overflow, 0)),
+        ("1h2x\0", err(TimeStatus::UnknownUnit, 3)),
+    ];
+
+    for (i, (s, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(
+            want,
+            unsafe { time_duration_parse(s.as_ptr().cast()) },
+            "#{i}"
+        );
+        let got = unsafe { time_duration_parse_bytes(s.as_ptr(), s.len() - 1) };
+        assert_eq!(want, got, "#{i}");
+    }
+    assert_eq!(err(TimeStatus::NullPointer, 0), unsafe {
+        time_duration_parse(ptr::null())
+    });
+
+    let mut buf = [1 as std::ffi::c_char; 32];
+    let n = unsafe { time_duration_format(i64::MIN, buf.as_mut_ptr(), buf.len()) };
+    assert_eq!(25, n);
+    let got = unsafe { CStr::from_ptr(buf.as_ptr()) };
+    assert_eq!(Ok("-2562047h47m16.854775808s"), got.to_str());
+
+    let n = unsafe { time_duration_format(HOUR.nanoseconds(), buf.as_mut_ptr(), 3) };
+    assert_eq!(6, n);
+    let got = unsafe { CStr::from_ptr(buf.as_ptr()) };
+    assert_eq!(Ok("1h"), got.to_str());
+    assert_eq!(6, unsafe {
+        time_duration_format(HOUR.nanoseconds(), ptr::null_mut(), 0)
+    });
+
+    let (h, s) = (HOUR.nanoseconds(), SECOND.nanoseconds());
+    assert_eq!(ok(HOUR + SECOND), time_duration_add(h, s));
+    assert_eq!(err(TimeStatus::Overflow, 0), time_duration_add(i64::MAX, 1));
+    assert_eq!(ok(HOUR - SECOND), time_duration_sub(h, s));
+    assert_eq!(err(TimeStatus::Overflow, 0), time_duration_sub(i64::MIN, 1));
+    assert_eq!(ok(3 * HOUR), time_duration_mul(h, 3));
+    assert_eq!(err(TimeStatus::Overflow, 0), time_duration_mul(i64::MAX, 2));
+    assert_eq!(h, time_duration_round(h - s, MINUTE.nanoseconds()));
+    assert_eq!(0, time_duration_truncate(h - s, h));
+    assert_eq!(1.5, time_duration_hours(h + h / 2));
+    assert_eq!(90.0, time_duration_minutes(h + h / 2));
+    assert_eq!(5400.0, time_duration_seconds(h + h / 2));
+}
+
+#[cfg(feature = "pyo3")]
+#[test]
+fn pyo3() {
+    use pyo3::exceptions::PyOverflowError;
+    use pyo3::prelude::*;
+    use pyo3::types::{PyDelta, PyDeltaAccess};
+
+    let test_vector = vec![
+        (Duration(0), (0, 0, 0), Duration(0)),
+        (Duration(1999), (0, 0, 1), MICROSECOND),
+        (
+            HOUR + 1500 * MILLISECOND,
+            (0, 3601, 500_000),
+            HOUR + 1500 * MILLISECOND,
+        ),
+        (-MICROSECOND, (-1, 86399, 999_999), -MICROSECOND),
+        (Duration(-1999), (-1, 86399, 999_999), -MICROSECOND),
+        (
+            Duration(i64::MAX),
+            (106751, 85636, 854_775),
+            Duration(i64::MAX - 807),
+        ),
+        (
+            Duration(i64::MIN),
+            (-106752, 763, 145_225),
+            Duration(i64::MIN + 808),
+        ),
+    ];
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        for (i, (d, (days, seconds, us), back)) in test_vector.into_iter().enumerate() {
+            let v = d.into_py(py);
+            let delta = v.downcast_bound::<PyDelta>(py).unwrap();
+            assert_eq!(days, delta.get_days(), "#{i}");
+            assert_eq!(seconds, delta.get_seconds(), "#{i}");
+            assert_eq!(us, delta.get_microseconds(), "#{i}");
+            assert_eq!(back, v.extract::<Duration>(py).unwrap(), "#{i}");
+        }
+
+        let v = PyDelta::new_bound(py, 106752, 0, 0, false).unwrap();
+        let err = v.extract::<Duration>().unwrap_err();
+        assert!(err.is_instance_of::<PyOverflowError>(py));
+
+        assert!(1i64.into_py(py).extract::<Duration>(py).is_err());
+    });
+}
+
+#[cfg(feature = "diesel")]
+#[test]
+fn diesel() {
+    use diesel::pg::Pg;
+    use diesel::prelude::*;
+
+    diesel::table! {
+        jobs (id) {
+            id -> Integer,
+            timeout -> BigInt,
+            backoff -> Interval,
+        }
+    }
+
+    let query = diesel::insert_into(jobs::table)
+        .values((jobs::timeout.eq(30 * SECOND), jobs::backoff.eq(MINUTE)));
+    assert_eq!(
+        r#"INSERT INTO "jobs" ("timeout", "backoff") VALUES ($1, $2) -- binds: [Duration(30000000000), Duration(60000000000)]"#,
+        diesel::debug_query::<Pg, _>(&query).to_string()
+    );
+}
+
+#[cfg(feature = "postgres-types")]
+#[test]
+fn postgres_types() {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    let interval = |microseconds: i64, days: i32, months: i32| {
+        let mut v = microseconds.to_be_bytes().to_vec();
+        v.extend(days.to_be_bytes());
+        v.extend(months.to_be_bytes());
+        v
+    };
+
+    let test_vector = vec![
+        (Duration(0), interval(0, 0, 0)),
+        (MICROSECOND, interval(1, 0, 0)),
+        (-HOUR - 30 * MINUTE, interval(-5_400_000_000, 0, 0)),
+    ];
+
+    for (i, (d, raw)) in test_vector.into_iter().enumerate() {
+        let mut out = BytesMut::new();
+        d.to_sql_checked(&Type::INTERVAL, &mut out).unwrap();
+        assert_eq!(raw, out.to_vec(), "#{i}");
+        assert_eq!(
+            d,
+            Duration::from_sql(&Type::INTERVAL, &raw).unwrap(),
+            "#{i}"
+        );
+    }
+
+    let mut out = BytesMut::new();
+    assert!(Duration(1).to_sql(&Type::INTERVAL, &mut out).is_err());
+    assert!(Duration(0).to_sql_checked(&Type::INT8, &mut out).is_err());
+
+    let test_vector = vec![
+        (interval(0, 1, 0), Some(24 * HOUR)),
+        (interval(3_600_000_000, -2, 0), Some(-47 * HOUR)),
+        (interval(0, 106_752, 0), None),
+        (interval(i64::MAX, 0, 0), None),
+        (interval(0, 0, 1), None),
+        (vec![0; 8], None),
+    ];
+
+    for (i, (raw, expect)) in test_vector.into_iter().enumerate() {
+        let got = Duration::from_sql(&Type::INTERVAL, &raw).ok();
+        assert_eq!(expect, got, "#{i}");
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite() {
+    use rusqlite::Connection;
+
+    let conn = Connection::open_in_memory().unwrap();
+
+    let test_vector = vec![
+        Duration(0),
+        Duration(1),
+        -HOUR - 30 * MINUTE,
+        Duration(i64::MAX),
+        Duration(i64::MIN),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        let (v, got): (i64, Duration) = conn
+            .query_row("SELECT ?1, ?1", [d], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap();
+        assert_eq!(d.nanoseconds(), v, "#{i}");
+        assert_eq!(d, got, "#{i}");
+    }
+
+    let got = conn.query_row("SELECT '1h'", [], |row| row.get::<_, Duration>(0));
+    assert!(got.is_err());
+}
+
+#[cfg(feature = "sqlx")]
+#[test]
+fn sqlx() {
+    use sqlx::postgres::types::PgInterval;
+
+    let interval = |days, microseconds| PgInterval {
+        months: 0,
+        days,
+        microseconds,
+    };
+
+    let test_vector = vec![
+        (Duration(0), interval(0, 0)),
+        (MICROSECOND, interval(0, 1)),
+        (-HOUR - 30 * MINUTE, interval(0, -5_400_000_000)),
+        (Duration(i64::MAX - 807), interval(0, i64::MAX / 1000)),
+    ];
+
+    for (i, (d, v)) in test_vector.into_iter().enumerate() {
+        assert_eq!(v, PgInterval::try_from(d).unwrap(), "#{i}");
+        assert_eq!(d, Duration::try_from(v).unwrap(), "#{i}");
+    }
+
+    assert!(PgInterval::try_from(Duration(1)).is_err());
+
+    let test_vector = vec![
+        (interval(1, 0), Some(24 * HOUR)),
+        (interval(-2, 3_600_000_000), Some(-47 * HOUR)),
+        (interval(106_752, 0), None),
+        (interval(0, i64::MAX), None),
+        (
+            PgInterval {
+                months: 1,
+                days: 0,
+                microseconds: 0,
+            },
+            None,
+        ),
+    ];
+
+    for (i, (v, expect)) in test_vector.into_iter().enumerate() {
+        assert_eq!(expect, Duration::try_from(v).ok(), "#{i}");
+    }
+}
+
+// test vectors
 lazy_static::lazy_static! {
   static ref PARSE_TESTS: Vec<ParseTest> = vec![
     // simple
@@ -248,7 +2507,11 @@ lazy_static::lazy_static! {
     // large value
     ("52763797000ns", 52763797000 * NANOSECOND),
     // more than 9 digits after decimal point, see https://golang.org/issue/6617
-    ("0.3333333333333333333h", 20 * MINUTE),
+    // The fraction is exact and truncated, where Go's float64 rounds it up to 20m.
+    ("0.3333333333333333333h", 20 * MINUTE - NANOSECOND),
+    ("0.33333333333333333334h", 20 * MINUTE - NANOSECOND),
+    ("0.5000000000000000001s", 500 * MILLISECOND),
+    ("1.0000000000000000000000000000000000000001h", HOUR),
     // 9007199254740993 = 1<<53+1 cannot be stored precisely in a float64
     ("9007199254740993ns", ((1<<53) + 1) * NANOSECOND),
     // largest duration that can be represented by int64 in nanoseconds
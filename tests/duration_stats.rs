@@ -0,0 +1,109 @@
+use time::{Duration, DurationStats, LongDuration, MICROSECOND, MILLISECOND, SECOND};
+
+#[test]
+fn empty() {
+    for (i, stats) in [DurationStats::new(), DurationStats::with_quantiles()]
+        .into_iter()
+        .enumerate()
+    {
+        assert_eq!(0, stats.count(), "#{i}");
+        assert_eq!(None, stats.min(), "#{i}");
+        assert_eq!(None, stats.max(), "#{i}");
+        assert_eq!(None, stats.mean(), "#{i}");
+        assert_eq!(None, stats.quantile(0.5), "#{i}");
+        assert_eq!(LongDuration(0), stats.sum(), "#{i}");
+    }
+}
+
+#[test]
+fn merge() {
+    let mut a = DurationStats::with_quantiles();
+    a.extend([SECOND, 3 * SECOND]);
+    let mut b = DurationStats::with_quantiles();
+    b.extend([-SECOND, 5 * SECOND]);
+
+    a.merge(&b);
+    assert_eq!(4, a.count());
+    assert_eq!(Some(-SECOND), a.min());
+    assert_eq!(Some(5 * SECOND), a.max());
+    assert_eq!(Some(2 * SECOND), a.mean());
+    assert_eq!(Some(-SECOND), a.quantile(0.0));
+    assert_eq!(Some(5 * SECOND), a.quantile(1.0));
+
+    // Merging stats without quantiles drops them.
+    a.merge(&DurationStats::from_iter([SECOND]));
+    assert_eq!(5, a.count());
+    assert_eq!(None, a.quantile(0.5));
+
+    let mut c = DurationStats::new();
+    c.merge(&b);
+    assert_eq!(2, c.count());
+    assert_eq!(Some(-SECOND), c.min());
+}
+
+#[test]
+fn quantile() {
+    let mut stats = DurationStats::with_quantiles();
+    for v in -1000..=10000 {
+        stats.push(v * MICROSECOND);
+    }
+    stats.push(Duration(i64::MAX));
+    stats.push(Duration(i64::MIN));
+
+    let test_vector = vec![
+        (0.0, Duration(i64::MIN)),
+        (0.05, (-450) * MICROSECOND),
+        (0.5, 4500 * MICROSECOND),
+        (0.9, 8900 * MICROSECOND),
+        (0.99, 9890 * MICROSECOND),
+        (1.0, Duration(i64::MAX)),
+    ];
+
+    for (i, (q, want)) in test_vector.into_iter().enumerate() {
+        let got = stats.quantile(q).unwrap();
+        let err = (got - want).abs().0 as f64 / want.abs().0 as f64;
+        assert!(err <= 1.0 / 32.0, "#{i} want {want}, got {got}");
+    }
+
+    // Small magnitudes are exact.
+    let stats: DurationStats = {
+        let mut stats = DurationStats::with_quantiles();
+        stats.extend((1..=31).map(Duration));
+        stats
+    };
+    assert_eq!(Some(Duration(16)), stats.quantile(0.5));
+    assert_eq!(Some(Duration(31)), stats.quantile(1.0));
+
+    // Without quantiles tracked.
+    let stats: DurationStats = (1..=31).map(Duration).collect();
+    assert_eq!(None, stats.quantile(0.5));
+}
+
+#[test]
+#[should_panic(expected = "quantile out of [0, 1]")]
+fn quantile_out_of_range() {
+    DurationStats::with_quantiles().quantile(1.5);
+}
+
+#[test]
+fn summary() {
+    let stats: DurationStats = [3 * MILLISECOND, MILLISECOND, 2 * MILLISECOND]
+        .into_iter()
+        .collect();
+
+    assert_eq!(3, stats.count());
+    assert_eq!(Some(MILLISECOND), stats.min());
+    assert_eq!(Some(3 * MILLISECOND), stats.max());
+    assert_eq!(Some(2 * MILLISECOND), stats.mean());
+    assert_eq!(LongDuration::from(6 * MILLISECOND), stats.sum());
+
+    // The sum may exceed the range of a Duration, but not the mean.
+    let stats: DurationStats = [Duration(i64::MAX), Duration(i64::MAX - 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(LongDuration(2 * i64::MAX as i128 - 2), stats.sum());
+    assert_eq!(Some(Duration(i64::MAX - 1)), stats.mean());
+
+    let stats: DurationStats = [-SECOND, Duration(0)].into_iter().collect();
+    assert_eq!(Some(-500 * MILLISECOND), stats.mean());
+}
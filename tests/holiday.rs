@@ -0,0 +1,123 @@
+use time::{Holiday, HolidayCalendar, Month, WeekendPolicy};
+
+#[test]
+fn add_business_days() {
+    let test_vector = vec![
+        ((2024, Month::March, 28), 1, (2024, Month::April, 2)),
+        ((2024, Month::April, 2), -1, (2024, Month::March, 28)),
+        ((2024, Month::December, 23), 2, (2024, Month::December, 27)),
+        ((2024, Month::December, 31), 1, (2025, Month::January, 2)),
+        ((2024, Month::June, 1), 0, (2024, Month::June, 1)),
+        ((2024, Month::June, 3), 5, (2024, Month::June, 10)),
+        ((2024, Month::June, 10), -5, (2024, Month::June, 3)),
+    ];
+
+    let c = HolidayCalendar::TARGET2;
+    for (i, ((year, month, day), n, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, c.add_business_days(year, month, day, n), "#{i}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "no business days in the week")]
+fn add_business_days_without_business_days() {
+    let all = WeekendPolicy::new(&[
+        time::Weekday::Sunday,
+        time::Weekday::Monday,
+        time::Weekday::Tuesday,
+        time::Weekday::Wednesday,
+        time::Weekday::Thursday,
+        time::Weekday::Friday,
+        time::Weekday::Saturday,
+    ]);
+    HolidayCalendar::new()
+        .with_weekend(all)
+        .add_business_days(2024, Month::January, 1, 1);
+}
+
+#[test]
+fn custom() {
+    let c = HolidayCalendar::new()
+        .with_holiday(Holiday::Easter { offset: 50 })
+        .with_weekend(WeekendPolicy::FRIDAY_SATURDAY);
+
+    assert_eq!(1, c.holidays().len());
+    assert_eq!(WeekendPolicy::FRIDAY_SATURDAY, c.weekend());
+    // Whit Monday.
+    assert!(c.is_holiday(2024, Month::May, 20));
+    assert!(!c.is_business_day(2024, Month::May, 17));
+    assert!(c.is_business_day(2024, Month::May, 19));
+
+    assert_eq!(HolidayCalendar::new(), HolidayCalendar::default());
+    assert_eq!(6, HolidayCalendar::TARGET2.holidays().len());
+}
+
+#[test]
+fn easter() {
+    let test_vector = vec![
+        (1818, Month::March, 22),
+        (1943, Month::April, 25),
+        (2000, Month::April, 23),
+        (2019, Month::April, 21),
+        (2024, Month::March, 31),
+        (2025, Month::April, 20),
+        (2038, Month::April, 25),
+    ];
+
+    let c = HolidayCalendar::new().with_holiday(Holiday::Easter { offset: 0 });
+    for (i, (year, month, day)) in test_vector.into_iter().enumerate() {
+        assert!(c.is_holiday(year, month, day), "#{i}");
+        assert!(!c.is_holiday(year, month, day - 1), "#{i}");
+    }
+}
+
+#[test]
+fn target2() {
+    let want = vec![
+        (Month::January, 1),
+        (Month::March, 29),
+        (Month::April, 1),
+        (Month::May, 1),
+        (Month::December, 25),
+        (Month::December, 26),
+    ];
+    assert_eq!(want, holidays(&HolidayCalendar::TARGET2, 2024));
+}
+
+#[test]
+fn us_federal() {
+    // Juneteenth and Christmas 2022 fell on a Sunday and were observed on
+    // Monday.
+    let want = vec![
+        (Month::January, 1),
+        (Month::January, 17),
+        (Month::February, 21),
+        (Month::May, 30),
+        (Month::June, 19),
+        (Month::June, 20),
+        (Month::July, 4),
+        (Month::September, 5),
+        (Month::October, 10),
+        (Month::November, 11),
+        (Month::November, 24),
+        (Month::December, 25),
+        (Month::December, 26),
+    ];
+    assert_eq!(want, holidays(&HolidayCalendar::US_FEDERAL, 2022));
+
+    // New Year's Day 2022 fell on a Saturday and was observed on Friday,
+    // December 31, 2021.
+    assert!(HolidayCalendar::US_FEDERAL.is_holiday(2021, Month::December, 31));
+    assert!(!HolidayCalendar::US_FEDERAL.is_business_day(2021, Month::December, 31));
+}
+
+/// Returns the holidays of `c` in `year`.
+fn holidays(c: &HolidayCalendar, year: i32) -> Vec<(Month, u8)> {
+    Month::iter()
+        .flat_map(|m| {
+            let (first, last) = m.bounds(year);
+            (1..=(last - first + 1) as u8).map(move |d| (m, d))
+        })
+        .filter(|&(m, d)| c.is_holiday(year, m, d))
+        .collect()
+}
@@ -0,0 +1,71 @@
+use time::{IsoWeekDate, Month, Weekday};
+
+#[test]
+fn from_calendar_date() {
+    let test_vector = vec![
+        ((2024, Month::January, 1), "2024-W01-1"),
+        ((2024, Month::December, 29), "2024-W52-7"),
+        ((2024, Month::December, 30), "2025-W01-1"),
+        ((2021, Month::January, 3), "2020-W53-7"),
+        ((2020, Month::December, 31), "2020-W53-4"),
+        ((2005, Month::January, 1), "2004-W53-6"),
+        ((2008, Month::December, 29), "2009-W01-1"),
+        ((2010, Month::January, 3), "2009-W53-7"),
+        ((1970, Month::January, 1), "1970-W01-4"),
+        ((0, Month::January, 1), "-0001-W52-6"),
+        ((-1, Month::December, 31), "-0001-W52-5"),
+    ];
+
+    for (i, ((year, month, day), want)) in test_vector.into_iter().enumerate() {
+        let d = IsoWeekDate::from_calendar_date(year, month, day).unwrap();
+        assert_eq!(want, d.to_string(), "#{i}");
+        assert_eq!((year, month, day), d.to_calendar_date(), "#{i}");
+    }
+
+    assert!(IsoWeekDate::from_calendar_date(2023, Month::February, 29).is_none());
+    assert!(IsoWeekDate::from_calendar_date(2024, Month::January, 0).is_none());
+}
+
+#[test]
+fn new() {
+    let d = IsoWeekDate::new(2020, 53, Weekday::Sunday).unwrap();
+    assert_eq!((2021, Month::January, 3), d.to_calendar_date());
+
+    assert!(IsoWeekDate::new(2021, 53, Weekday::Monday).is_none());
+    assert!(IsoWeekDate::new(2021, 0, Weekday::Monday).is_none());
+}
+
+#[test]
+fn ord() {
+    let a = IsoWeekDate::new(2024, 1, Weekday::Saturday).unwrap();
+    let b = IsoWeekDate::new(2024, 1, Weekday::Sunday).unwrap();
+    let c = IsoWeekDate::new(2024, 2, Weekday::Monday).unwrap();
+
+    assert!(a < b && b < c);
+}
+
+#[test]
+fn round_trip() {
+    // Each day of 1999 through 2030 maps to the next week date.
+    let mut prev = IsoWeekDate::from_calendar_date(1998, Month::December, 31).unwrap();
+    for year in 1999..=2030 {
+        for month in Month::iter() {
+            let (first, last) = month.bounds(year);
+            for day in 1..=(last - first + 1) as u8 {
+                let d = IsoWeekDate::from_calendar_date(year, month, day).unwrap();
+                assert_eq!(prev.to_unix_days() + 1, d.to_unix_days(), "{d}");
+                assert_eq!((year, month, day), d.to_calendar_date(), "{d}");
+                assert!(prev < d, "{d}");
+                prev = d;
+            }
+        }
+    }
+}
+
+#[test]
+fn weeks_in_year() {
+    let long_years: Vec<i32> = (2000..=2040)
+        .filter(|&y| IsoWeekDate::weeks_in_year(y) == 53)
+        .collect();
+    assert_eq!(vec![2004, 2009, 2015, 2020, 2026, 2032, 2037], long_years);
+}
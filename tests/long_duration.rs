@@ -0,0 +1,121 @@
+use time::{Duration, LongDuration, HOUR, MINUTE, NANOSECOND, SECOND};
+
+#[test]
+fn abs() {
+    let test_vector = vec![
+        (0, 0),
+        (1, 1),
+        (-1, 1),
+        (i128::MIN, i128::MAX),
+        (i128::MIN + 1, i128::MAX),
+        (i128::MAX, i128::MAX),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(LongDuration(want), LongDuration(d).abs(), "#{i}");
+    }
+}
+
+#[test]
+fn from_duration() {
+    let test_vector = vec![
+        Duration(0),
+        NANOSECOND,
+        -HOUR,
+        Duration(i64::MAX),
+        Duration(i64::MIN),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        let long = LongDuration::from(d);
+        assert_eq!(d.0 as i128, long.nanoseconds(), "#{i}");
+        assert_eq!(
+            Some(d),
+            Duration::try_from(long).ok(),
+            "#{i} round trip {d}"
+        );
+    }
+}
+
+#[test]
+fn hours() {
+    let test_vector = vec![
+        (LongDuration::from(-HOUR), -1.0),
+        (LongDuration::from(HOUR), 1.0),
+        (LongDuration(1_000_000 * HOUR.0 as i128), 1e6),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.hours(), "#{i}");
+    }
+}
+
+#[test]
+fn ops() {
+    let max = LongDuration::from(Duration(i64::MAX));
+    let one = LongDuration::from(NANOSECOND);
+
+    assert_eq!(LongDuration(i64::MAX as i128 + 1), max + one);
+    assert_eq!(LongDuration(i64::MAX as i128 - 1), max - one);
+    assert_eq!(LongDuration(-(i64::MAX as i128)), -max);
+    assert_eq!(LongDuration(i128::MIN), -LongDuration(i128::MIN));
+    assert!(max < max + one);
+}
+
+#[test]
+fn seconds() {
+    let test_vector = vec![
+        (LongDuration::from(300 * time::MILLISECOND), 0.3),
+        (LongDuration(-1_000_000_000_000 * SECOND.0 as i128), -1e12),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.seconds(), "#{i}");
+    }
+}
+
+#[test]
+fn to_string() {
+    let test_vector = vec![
+        ("0s", LongDuration(0)),
+        ("1.1µs", LongDuration(1100)),
+        ("4m5s", LongDuration::from(4 * MINUTE + 5 * SECOND)),
+        ("2562047h47m16.854775807s", LongDuration(i64::MAX as i128)),
+        ("-2562047h47m16.854775808s", LongDuration(i64::MIN as i128)),
+        (
+            "2562047h47m16.854775808s",
+            LongDuration(i64::MAX as i128 + 1),
+        ),
+        (
+            "-2562047h47m16.854775809s",
+            LongDuration(i64::MIN as i128 - 1),
+        ),
+        ("8760000h0m0s", LongDuration(8_760_000 * HOUR.0 as i128)),
+        (
+            "47261439850130342147690917h41m55.884105727s",
+            LongDuration(i128::MAX),
+        ),
+        (
+            "-47261439850130342147690917h41m55.884105728s",
+            LongDuration(i128::MIN),
+        ),
+    ];
+
+    for (i, (want, d)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.to_string(), "#{i}");
+    }
+}
+
+#[test]
+fn try_into_duration() {
+    let test_vector = vec![
+        LongDuration(i64::MAX as i128 + 1),
+        LongDuration(i64::MIN as i128 - 1),
+        LongDuration(i128::MAX),
+        LongDuration(i128::MIN),
+    ];
+
+    for (i, d) in test_vector.into_iter().enumerate() {
+        assert!(Duration::try_from(d).is_err(), "#{i}");
+    }
+}
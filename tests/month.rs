@@ -0,0 +1,176 @@
+use time::Month;
+
+#[test]
+fn abbr() {
+    let test_vector = vec![
+        (Month::January, "Jan"),
+        (Month::February, "Feb"),
+        (Month::March, "Mar"),
+        (Month::April, "Apr"),
+        (Month::May, "May"),
+        (Month::June, "Jun"),
+        (Month::July, "Jul"),
+        (Month::August, "Aug"),
+        (Month::September, "Sep"),
+        (Month::October, "Oct"),
+        (Month::November, "Nov"),
+        (Month::December, "Dec"),
+    ];
+
+    for (i, (m, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, m.abbr(), "#{i}");
+        assert!(m.to_string().starts_with(want), "#{i}");
+    }
+}
+
+#[test]
+fn bounds() {
+    let test_vector = vec![
+        (Month::January, 2023, (1, 31)),
+        (Month::February, 2023, (32, 59)),
+        (Month::March, 2023, (60, 90)),
+        (Month::December, 2023, (335, 365)),
+        (Month::January, 2024, (1, 31)),
+        (Month::February, 2024, (32, 60)),
+        (Month::March, 2024, (61, 91)),
+        (Month::December, 2024, (336, 366)),
+        (Month::February, 1900, (32, 59)),
+        (Month::February, 2000, (32, 60)),
+        (Month::February, -4, (32, 60)),
+    ];
+
+    for (i, (m, year, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, m.bounds(year), "#{i}");
+    }
+
+    for year in [2023, 2024] {
+        let mut next = 1;
+        for m in Month::iter() {
+            let (first, last) = m.bounds(year);
+            assert_eq!(next, first, "{m} {year}");
+            next = last + 1;
+        }
+    }
+}
+
+#[test]
+fn iter() {
+    let got: Vec<i32> = Month::iter().map(i32::from).collect();
+    assert_eq!((1..=12).collect::<Vec<_>>(), got);
+
+    let got: Vec<i32> = Month::iter().rev().map(i32::from).collect();
+    assert_eq!((1..=12).rev().collect::<Vec<_>>(), got);
+
+    assert_eq!(12, Month::iter().len());
+}
+
+#[test]
+fn numbers() {
+    let test_vector = vec![
+        (1, Month::January),
+        (2, Month::February),
+        (3, Month::March),
+        (4, Month::April),
+        (5, Month::May),
+        (6, Month::June),
+        (7, Month::July),
+        (8, Month::August),
+        (9, Month::September),
+        (10, Month::October),
+        (11, Month::November),
+        (12, Month::December),
+    ];
+
+    for (i, (v, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, Month::try_from(v).unwrap(), "#{i}");
+        assert_eq!(want, Month::try_from(v as u8).unwrap(), "#{i}");
+        assert_eq!(v, i32::from(want), "#{i}");
+    }
+
+    for (i, v) in [0, 13, -1, i32::MIN, i32::MAX].into_iter().enumerate() {
+        assert!(Month::try_from(v).is_err(), "#{i}");
+    }
+    for (i, v) in [0u8, 13, u8::MAX].into_iter().enumerate() {
+        assert!(Month::try_from(v).is_err(), "#{i}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Number(#[serde(with = "time::serde::month_number")] Month);
+
+    for (i, m) in Month::iter().enumerate() {
+        let name = format!("\"{m}\"");
+        let number = (i + 1).to_string();
+
+        assert_eq!(name, serde_json::to_string(&m).unwrap(), "#{i}");
+        assert_eq!(m, serde_json::from_str::<Month>(&name).unwrap(), "#{i}");
+        assert_eq!(m, serde_json::from_str::<Month>(&number).unwrap(), "#{i}");
+
+        assert_eq!(number, serde_json::to_string(&Number(m)).unwrap(), "#{i}");
+        let got: Number = serde_json::from_str(&number).unwrap();
+        assert_eq!(m, got.0, "#{i}");
+    }
+
+    for (i, input) in ["0", "13", "-1", r#""january""#, r#""Jan""#, "null"]
+        .into_iter()
+        .enumerate()
+    {
+        assert!(serde_json::from_str::<Month>(input).is_err(), "#{i}");
+    }
+    assert!(serde_json::from_str::<Number>(r#""January""#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_binary() {
+    for (i, m) in Month::iter().enumerate() {
+        let encoded = bincode::serialize(&m).unwrap();
+        assert_eq!(vec![m as u8], encoded, "#{i}");
+
+        let got: Month = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(m, got, "#{i}");
+    }
+
+    assert!(bincode::deserialize::<Month>(&[13]).is_err());
+}
+
+#[test]
+fn quarter() {
+    let want = [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4];
+
+    for (i, (m, want)) in Month::iter().zip(want).enumerate() {
+        assert_eq!(want, m.quarter(), "#{i}");
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    for (i, v) in Month::iter().enumerate() {
+        let c = chrono::Month::from(v);
+        assert_eq!(i32::from(v) as u32, c.number_from_month(), "#{i}");
+        assert_eq!(v, Month::from(c), "#{i}");
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn fuzz_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let test_vector = vec![
+        (vec![], Month::January),
+        (vec![0], Month::January),
+        (vec![11], Month::December),
+        (vec![12], Month::January),
+        (vec![0xff], Month::April),
+    ];
+
+    for (i, (data, want)) in test_vector.into_iter().enumerate() {
+        let got = Month::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(want, got, "#{i}");
+    }
+}
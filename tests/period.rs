@@ -0,0 +1,129 @@
+use time::{Period, PeriodParseError};
+
+#[test]
+fn from_str() {
+    let test_vector = vec![
+        ("0", Period::ZERO),
+        ("0d", Period::ZERO),
+        ("1y", Period::new(1, 0, 0)),
+        ("1y2mo3d", Period::new(1, 2, 3)),
+        ("14mo", Period::new(0, 14, 0)),
+        ("2w", Period::new(0, 0, 14)),
+        ("1w3d", Period::new(0, 0, 10)),
+        ("+1mo", Period::new(0, 1, 0)),
+        ("-1y2mo", Period::new(-1, -2, 0)),
+        ("1y-2mo", Period::new(1, -2, 0)),
+        ("-1y-2mo", Period::new(-1, 2, 0)),
+        ("-2147483648d", Period::new(0, 0, i32::MIN)),
+        ("P1Y2M3D", Period::new(1, 2, 3)),
+        ("P2W", Period::new(0, 0, 14)),
+        ("p1M", Period::new(0, 1, 0)),
+        ("p1y", Period::new(1, 0, 0)),
+        ("P1y2m3d", Period::new(1, 2, 3)),
+        ("-p2w", Period::new(0, 0, -14)),
+        ("-P1Y2M", Period::new(-1, -2, 0)),
+        ("P0D", Period::ZERO),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(Ok(want), input.parse(), "#{i} parse '{input}'");
+    }
+}
+
+#[test]
+fn from_str_error() {
+    let test_vector = vec![
+        ("", PeriodParseError::Invalid { offset: 0 }),
+        ("-", PeriodParseError::Invalid { offset: 1 }),
+        ("P", PeriodParseError::Invalid { offset: 1 }),
+        ("1", PeriodParseError::Invalid { offset: 1 }),
+        ("y", PeriodParseError::Invalid { offset: 0 }),
+        ("1m", PeriodParseError::Invalid { offset: 1 }),
+        ("1d2y", PeriodParseError::Invalid { offset: 3 }),
+        ("1y1y", PeriodParseError::Invalid { offset: 3 }),
+        ("1.5y", PeriodParseError::Invalid { offset: 1 }),
+        ("P1YT1H", PeriodParseError::Invalid { offset: 3 }),
+        ("2147483648d", PeriodParseError::Overflow { offset: 0 }),
+        ("306783378w2d", PeriodParseError::Overflow { offset: 10 }),
+        (
+            "99999999999999999999y",
+            PeriodParseError::Overflow { offset: 0 },
+        ),
+    ];
+
+    for (i, (input, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(Err(want), input.parse::<Period>(), "#{i} parse '{input}'");
+    }
+
+    assert_eq!(
+        Err(PeriodParseError::Invalid { offset: 0 }),
+        Period::parse_iso8601("1y")
+    );
+    assert_eq!(
+        Err(PeriodParseError::Invalid { offset: 1 }),
+        Period::parse_iso8601("-1y")
+    );
+}
+
+#[test]
+fn normalized() {
+    let test_vector = vec![
+        (Period::new(1, 14, 40), Period::new(2, 2, 40)),
+        (Period::new(1, -2, 0), Period::new(0, 10, 0)),
+        (Period::new(-1, 2, 0), Period::new(0, -10, 0)),
+        (Period::new(0, -25, 0), Period::new(-2, -1, 0)),
+        (Period::new(0, 12, 0), Period::new(1, 0, 0)),
+    ];
+
+    for (i, (p, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, p.normalized(), "#{i}");
+        assert_eq!(p.total_months(), want.total_months(), "#{i}");
+    }
+}
+
+#[test]
+fn ops() {
+    let (a, b) = (Period::new(1, 2, 3), Period::new(0, 11, -5));
+
+    assert_eq!(Period::new(1, 13, -2), a + b);
+    assert_eq!(Period::new(1, -9, 8), a - b);
+    assert_eq!(Period::new(-1, -2, -3), -a);
+    assert_eq!(Period::new(3, 6, 9), a * 3);
+    assert!((a - a).is_zero());
+
+    let max = Period::new(0, 0, i32::MAX);
+    assert_eq!(Some(a + b), a.checked_add(b));
+    assert_eq!(None, max.checked_add(Period::new(0, 0, 1)));
+    assert_eq!(Some(a - b), a.checked_sub(b));
+    assert_eq!(None, (-max).checked_sub(Period::new(0, 0, 2)));
+    assert_eq!(Some(a * 3), a.checked_mul(3));
+    assert_eq!(None, max.checked_mul(2));
+    assert_eq!(Some(-a), a.checked_neg());
+    assert_eq!(None, Period::new(i32::MIN, 0, 0).checked_neg());
+}
+
+#[test]
+#[should_panic(expected = "period overflow")]
+fn ops_overflow() {
+    let _ = Period::new(0, i32::MAX, 0) + Period::new(0, 1, 0);
+}
+
+#[test]
+fn to_string() {
+    let test_vector = vec![
+        (Period::ZERO, "0d", "P0D"),
+        (Period::new(1, 2, 3), "1y2mo3d", "P1Y2M3D"),
+        (Period::new(0, 0, 14), "14d", "P14D"),
+        (Period::new(-1, -2, 0), "-1y2mo", "-P1Y2M"),
+        (Period::new(1, -2, 0), "1y-2mo", "P1Y-2M"),
+        (Period::new(-1, 2, 0), "-1y-2mo", "-P1Y-2M"),
+        (Period::new(0, 0, i32::MIN), "-2147483648d", "-P2147483648D"),
+    ];
+
+    for (i, (p, want, want_iso)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, p.to_string(), "#{i}");
+        assert_eq!(want_iso, p.to_iso8601(), "#{i}");
+        assert_eq!(Ok(p), want.parse(), "#{i}");
+        assert_eq!(Ok(p), Period::parse_iso8601(want_iso), "#{i}");
+    }
+}
@@ -0,0 +1,218 @@
+use time::{Month, Weekday, WeekendPolicy};
+
+#[test]
+fn abbr() {
+    let test_vector = vec![
+        (Weekday::Sunday, "Sun"),
+        (Weekday::Monday, "Mon"),
+        (Weekday::Tuesday, "Tue"),
+        (Weekday::Wednesday, "Wed"),
+        (Weekday::Thursday, "Thu"),
+        (Weekday::Friday, "Fri"),
+        (Weekday::Saturday, "Sat"),
+    ];
+
+    for (i, (d, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.abbr(), "#{i}");
+        assert!(d.to_string().starts_with(want), "#{i}");
+    }
+}
+
+#[test]
+fn from_unix_days() {
+    let test_vector = vec![
+        (0, Weekday::Thursday),
+        (1, Weekday::Friday),
+        (3, Weekday::Sunday),
+        (-1, Weekday::Wednesday),
+        (-4, Weekday::Sunday),
+        (-7, Weekday::Thursday),
+        (19_723, Weekday::Monday),
+        (-719_468, Weekday::Wednesday),
+        (i64::MAX, Weekday::Thursday),
+        (i64::MIN, Weekday::Wednesday),
+    ];
+
+    for (i, (days, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, Weekday::from_unix_days(days), "#{i}");
+    }
+}
+
+#[test]
+fn is_weekend() {
+    let test_vector = vec![
+        (WeekendPolicy::SATURDAY_SUNDAY, vec![0, 6]),
+        (WeekendPolicy::FRIDAY_SATURDAY, vec![5, 6]),
+        (WeekendPolicy::SUNDAY, vec![0]),
+        (WeekendPolicy::new(&[]), vec![]),
+        (WeekendPolicy::default(), vec![0, 6]),
+    ];
+
+    for (i, (policy, want)) in test_vector.into_iter().enumerate() {
+        let got: Vec<i32> = Weekday::iter()
+            .filter(|d| d.is_weekend_in(policy))
+            .map(i32::from)
+            .collect();
+        assert_eq!(want, got, "#{i}");
+    }
+
+    let got: Vec<i32> = Weekday::iter()
+        .filter(Weekday::is_weekend)
+        .map(i32::from)
+        .collect();
+    assert_eq!(vec![0, 6], got);
+}
+
+#[test]
+fn iter() {
+    let got: Vec<i32> = Weekday::iter().map(i32::from).collect();
+    assert_eq!(vec![0, 1, 2, 3, 4, 5, 6], got);
+
+    let got: Vec<i32> = Weekday::iter().rev().map(i32::from).collect();
+    assert_eq!(vec![6, 5, 4, 3, 2, 1, 0], got);
+
+    let test_vector = vec![
+        (Weekday::Sunday, vec![0, 1, 2, 3, 4, 5, 6]),
+        (Weekday::Monday, vec![1, 2, 3, 4, 5, 6, 0]),
+        (Weekday::Saturday, vec![6, 0, 1, 2, 3, 4, 5]),
+    ];
+
+    for (i, (start, want)) in test_vector.into_iter().enumerate() {
+        let got: Vec<i32> = Weekday::iter_from(start).map(i32::from).collect();
+        assert_eq!(want, got, "#{i}");
+        assert_eq!(7, Weekday::iter_from(start).len(), "#{i}");
+    }
+}
+
+#[test]
+fn nth_of_month() {
+    let test_vector = vec![
+        (Weekday::Sunday, 2024, Month::March, 2, Some(10)),
+        (Weekday::Sunday, 2024, Month::November, 1, Some(3)),
+        (Weekday::Friday, 2024, Month::March, -1, Some(29)),
+        (Weekday::Friday, 2024, Month::March, 5, Some(29)),
+        (Weekday::Friday, 2024, Month::March, -5, Some(1)),
+        (Weekday::Friday, 2024, Month::March, 6, None),
+        (Weekday::Friday, 2024, Month::March, -6, None),
+        (Weekday::Monday, 2024, Month::May, -1, Some(27)),
+        (Weekday::Thursday, 2024, Month::February, 5, Some(29)),
+        (Weekday::Thursday, 2023, Month::February, 5, None),
+        (Weekday::Thursday, 2023, Month::February, -1, Some(23)),
+        (Weekday::Saturday, 2000, Month::January, 1, Some(1)),
+        (Weekday::Friday, 1900, Month::December, -1, Some(28)),
+        (Weekday::Tuesday, -1, Month::January, 1, Some(5)),
+        (Weekday::Tuesday, 2024, Month::January, 0, None),
+    ];
+
+    for (i, (d, year, month, n, want)) in test_vector.into_iter().enumerate() {
+        assert_eq!(want, d.nth_of_month(year, month, n), "#{i}");
+    }
+}
+
+#[test]
+fn numbers() {
+    let test_vector = vec![
+        (Weekday::Sunday, 0, 7),
+        (Weekday::Monday, 1, 1),
+        (Weekday::Tuesday, 2, 2),
+        (Weekday::Wednesday, 3, 3),
+        (Weekday::Thursday, 4, 4),
+        (Weekday::Friday, 5, 5),
+        (Weekday::Saturday, 6, 6),
+    ];
+
+    for (i, (d, go, iso)) in test_vector.into_iter().enumerate() {
+        assert_eq!(d, Weekday::try_from(go).unwrap(), "#{i}");
+        assert_eq!(go, i32::from(d), "#{i}");
+        assert_eq!(d, Weekday::from_iso(iso).unwrap(), "#{i}");
+        assert_eq!(iso, d.to_iso(), "#{i}");
+    }
+
+    for (i, v) in [-1, 7, i32::MIN, i32::MAX].into_iter().enumerate() {
+        assert!(Weekday::try_from(v).is_err(), "#{i}");
+    }
+    for (i, v) in [0, 8, u8::MAX].into_iter().enumerate() {
+        assert!(Weekday::from_iso(v).is_err(), "#{i}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Number(#[serde(with = "time::serde::weekday_number")] Weekday);
+
+    let test_vector = vec![
+        (Weekday::Sunday, "Sunday", 0),
+        (Weekday::Monday, "Monday", 1),
+        (Weekday::Tuesday, "Tuesday", 2),
+        (Weekday::Wednesday, "Wednesday", 3),
+        (Weekday::Thursday, "Thursday", 4),
+        (Weekday::Friday, "Friday", 5),
+        (Weekday::Saturday, "Saturday", 6),
+    ];
+
+    for (i, (d, name, number)) in test_vector.into_iter().enumerate() {
+        let name = format!("\"{name}\"");
+        let number = number.to_string();
+
+        assert_eq!(name, serde_json::to_string(&d).unwrap(), "#{i}");
+        assert_eq!(d, serde_json::from_str::<Weekday>(&name).unwrap(), "#{i}");
+        assert_eq!(d, serde_json::from_str::<Weekday>(&number).unwrap(), "#{i}");
+
+        assert_eq!(number, serde_json::to_string(&Number(d)).unwrap(), "#{i}");
+        let got: Number = serde_json::from_str(&number).unwrap();
+        assert_eq!(d, got.0, "#{i}");
+    }
+
+    for (i, input) in ["7", "-1", r#""sunday""#, r#""Sun""#, "null"]
+        .into_iter()
+        .enumerate()
+    {
+        assert!(serde_json::from_str::<Weekday>(input).is_err(), "#{i}");
+    }
+    assert!(serde_json::from_str::<Number>(r#""Sunday""#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_binary() {
+    for (i, d) in Weekday::iter().enumerate() {
+        let encoded = bincode::serialize(&d).unwrap();
+        assert_eq!(vec![d as u8], encoded, "#{i}");
+
+        let got: Weekday = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(d, got, "#{i}");
+    }
+
+    assert!(bincode::deserialize::<Weekday>(&[7]).is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    for (i, v) in Weekday::iter().enumerate() {
+        let c = chrono::Weekday::from(v);
+        assert_eq!(i32::from(v) as u32, c.num_days_from_sunday(), "#{i}");
+        assert_eq!(v, Weekday::from(c), "#{i}");
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn fuzz_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let test_vector = vec![
+        (vec![], Weekday::Sunday),
+        (vec![0], Weekday::Sunday),
+        (vec![6], Weekday::Saturday),
+        (vec![7], Weekday::Sunday),
+        (vec![0xff], Weekday::Wednesday),
+    ];
+
+    for (i, (data, want)) in test_vector.into_iter().enumerate() {
+        let got = Weekday::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(want, got, "#{i}");
+    }
+}